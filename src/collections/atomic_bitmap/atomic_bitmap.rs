@@ -0,0 +1,323 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::{
+    collections::{
+        bitmap::Bitmap,
+        raw_array::RawArray,
+    },
+    error::{
+        Error,
+        ErrorCode,
+    },
+};
+use ::core::sync::atomic::{
+    AtomicUsize,
+    Ordering,
+};
+
+//==================================================================================================
+// Structures
+//==================================================================================================
+
+///
+/// # Description
+///
+/// A bitmap that can be shared and modified concurrently without locks.
+///
+/// Bits are stored in atomic machine words and all operations are carried out with atomic
+/// read-modify-write instructions, thus they only require a shared reference to the bitmap. Bits
+/// that lie past the end of the bitmap in the last word are always kept cleared.
+///
+#[derive(Debug)]
+pub struct AtomicBitmap {
+    /// Capacity of the bitmap (in bits).
+    number_of_bits: usize,
+    /// Number of bits set in the bitmap.
+    usage: AtomicUsize,
+    /// Index of a word that likely has a cleared bit. This is only a hint.
+    next_free: AtomicUsize,
+    /// Underlying bits.
+    bits: RawArray<AtomicUsize>,
+}
+
+// SAFETY: the underlying storage is exclusively owned by the bitmap, and it is only accessed through
+// atomic operations.
+unsafe impl Send for AtomicBitmap {}
+
+// SAFETY: the underlying storage is exclusively owned by the bitmap, and it is only accessed through
+// atomic operations.
+unsafe impl Sync for AtomicBitmap {}
+
+//==================================================================================================
+// Implementations
+//==================================================================================================
+
+impl AtomicBitmap {
+    /// Number of bits in a word of the bitmap.
+    const WORD_BITS: usize = usize::BITS as usize;
+
+    ///
+    /// # Description
+    ///
+    /// Creates a new atomic bitmap with a given length. The bitmap is initialized with all bits set
+    /// to zero.
+    ///
+    /// # Parameters
+    ///
+    /// - `len`: Length of the bitmap in bits.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, a new atomic bitmap is returned. Upon failure, an error is returned instead.
+    ///
+    pub fn new(len: usize) -> Result<Self, Error> {
+        // Check if the length is invalid.
+        if len == 0 || len >= i32::MAX as usize {
+            return Err(Error::new(ErrorCode::InvalidArgument, "invalid length"));
+        }
+
+        // Allocate the bitmap.
+        let array: RawArray<AtomicUsize> = RawArray::new(Bitmap::words_for(len))?;
+
+        Self::from_raw_array(array, len)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Creates a new atomic bitmap from a raw array of atomic words. The bitmap is initialized with
+    /// all bits set to zero.
+    ///
+    /// # Parameters
+    ///
+    /// - `array`: Raw array to create the bitmap from.
+    /// - `len`: Length of the bitmap in bits.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, a new atomic bitmap is returned. Upon failure, an error is returned instead.
+    ///
+    pub fn from_raw_array(array: RawArray<AtomicUsize>, len: usize) -> Result<Self, Error> {
+        // Check if the length is invalid.
+        if len == 0 || len > array.len() * Self::WORD_BITS {
+            return Err(Error::new(ErrorCode::InvalidArgument, "invalid length"));
+        }
+
+        // Zero out the bitmap.
+        for word in array.iter() {
+            word.store(0, Ordering::Relaxed);
+        }
+
+        Ok(Self {
+            number_of_bits: len,
+            usage: AtomicUsize::new(0),
+            next_free: AtomicUsize::new(0),
+            bits: array,
+        })
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the number of bits in the bitmap.
+    ///
+    /// # Returns
+    ///
+    /// The number of bits in the bitmap.
+    ///
+    pub fn number_of_bits(&self) -> usize {
+        self.number_of_bits
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the number of bits that are set in the bitmap. If the bitmap is concurrently
+    /// modified, the returned value may already be stale.
+    ///
+    /// # Returns
+    ///
+    /// The number of bits that are set in the bitmap.
+    ///
+    pub fn count_ones(&self) -> usize {
+        self.usage.load(Ordering::Relaxed)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Allocates a bit in the bitmap.
+    ///
+    /// Words are scanned one at a time, thus a bit that is cleared in a word that was already
+    /// scanned may be missed. The bitmap is then scanned again, unless all of its bits are
+    /// accounted as set, so that allocation fails only if the bitmap is full.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, the index of the allocated bit is returned. Upon failure, an error is returned
+    /// instead.
+    ///
+    pub fn alloc(&self) -> Result<usize, Error> {
+        loop {
+            if let Some(index) = self.try_alloc() {
+                return Ok(index);
+            }
+
+            // Check if the bitmap is full.
+            if self.usage.load(Ordering::Relaxed) >= self.number_of_bits {
+                return Err(Error::new(ErrorCode::OutOfMemory, "bitmap is full"));
+            }
+        }
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Attempts to allocate a bit in the bitmap, scanning each word once.
+    ///
+    /// # Returns
+    ///
+    /// The index of the allocated bit, if a cleared bit was found.
+    ///
+    fn try_alloc(&self) -> Option<usize> {
+        let num_words: usize = self.bits.len();
+        let hint: usize = self.next_free.load(Ordering::Relaxed) % num_words;
+
+        // Traverse the bitmap one word at a time, starting at the hint and wrapping around.
+        for i in (hint..num_words).chain(0..hint) {
+            let mut word: usize = self.bits[i].load(Ordering::Acquire);
+
+            // Attempt to set a cleared bit in this word, until it becomes full.
+            while word != usize::MAX {
+                // Find the lowest free bit.
+                let bit: usize = word.trailing_ones() as usize;
+
+                // Check if the free bit lies past the end of the bitmap.
+                if i * Self::WORD_BITS + bit >= self.number_of_bits {
+                    break;
+                }
+
+                // Attempt to allocate the bit.
+                match self.bits[i].compare_exchange_weak(
+                    word,
+                    word | (1 << bit),
+                    Ordering::AcqRel,
+                    Ordering::Acquire,
+                ) {
+                    Ok(_) => {
+                        self.usage.fetch_add(1, Ordering::Relaxed);
+                        self.next_free.store(i, Ordering::Relaxed);
+                        return Some(i * Self::WORD_BITS + bit);
+                    },
+                    // Another core modified the word, thus try again with its current value.
+                    Err(current) => word = current,
+                }
+            }
+        }
+
+        None
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Sets a bit at a given index in the bitmap.
+    ///
+    /// # Parameters
+    ///
+    /// - `index`: Index of the bit to set.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, `Ok(())` is returned. Upon failure, an error is returned instead.
+    ///
+    pub fn set(&self, index: usize) -> Result<(), Error> {
+        let (word, bit): (usize, usize) = self.index(index)?;
+        let old: usize = self.bits[word].fetch_or(1 << bit, Ordering::AcqRel);
+
+        // Check if the bit was already set.
+        if old & (1 << bit) != 0 {
+            return Err(Error::new(ErrorCode::ResourceBusy, "bit is already set"));
+        }
+
+        self.usage.fetch_add(1, Ordering::Relaxed);
+        Ok(())
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Clears a bit at a given index in the bitmap.
+    ///
+    /// # Parameters
+    ///
+    /// - `index`: Index of the bit to clear.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, `Ok(())` is returned. Upon failure, an error is returned instead.
+    ///
+    pub fn clear(&self, index: usize) -> Result<(), Error> {
+        let (word, bit): (usize, usize) = self.index(index)?;
+        let old: usize = self.bits[word].fetch_and(!(1 << bit), Ordering::AcqRel);
+
+        // Check if the bit was already cleared.
+        if old & (1 << bit) == 0 {
+            return Err(Error::new(ErrorCode::BadAddress, "bit is already cleared"));
+        }
+
+        self.usage.fetch_sub(1, Ordering::Relaxed);
+        self.next_free.fetch_min(word, Ordering::Relaxed);
+        Ok(())
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Tests a bit at a given index in the bitmap.
+    ///
+    /// # Parameters
+    ///
+    /// - `index`: Index of the bit to test.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, `Ok(true)` is returned if the bit is set, `Ok(false)` is returned otherwise.
+    /// Upon failure, an error is returned instead.
+    ///
+    pub fn test(&self, index: usize) -> Result<bool, Error> {
+        let (word, bit): (usize, usize) = self.index(index)?;
+        Ok((self.bits[word].load(Ordering::Acquire) & (1 << bit)) != 0)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the `(word, bit)` pair of a index.
+    ///
+    /// # Parameters
+    ///
+    /// - `index`: Index of the bit.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, the `(word, bit)` pair of the index is returned. Upon
+    /// failure, an error is returned instead.
+    ///
+    fn index(&self, index: usize) -> Result<(usize, usize), Error> {
+        // Check if the index is out of bounds.
+        if index >= self.number_of_bits {
+            return Err(Error::new(ErrorCode::InvalidArgument, "index out of bounds"));
+        }
+
+        let word: usize = index / Self::WORD_BITS;
+        let bit: usize = index % Self::WORD_BITS;
+
+        Ok((word, bit))
+    }
+}
@@ -0,0 +1,17 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Modules
+//==================================================================================================
+
+mod atomic_bitmap;
+
+#[cfg(test)]
+mod test;
+
+//==================================================================================================
+// Exports
+//==================================================================================================
+
+pub use atomic_bitmap::*;
@@ -0,0 +1,136 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+extern crate std;
+
+use crate::{
+    collections::AtomicBitmap,
+    error::ErrorCode,
+};
+use ::alloc::vec::Vec;
+use std::thread;
+
+//==================================================================================================
+// Unit Tests
+//==================================================================================================
+
+/// Number of threads used in stress tests.
+const NUM_THREADS: usize = 8;
+
+/// Number of bits allocated by each thread in stress tests.
+const BITS_PER_THREAD: usize = 512;
+
+/// Attempts to set, test and clear bits in an [`AtomicBitmap`].
+#[test]
+fn test_set_test_and_clear() {
+    let bitmap: AtomicBitmap = match AtomicBitmap::new(usize::BITS as usize + 1) {
+        Ok(bitmap) => bitmap,
+        Err(_) => panic!("failed to create bitmap"),
+    };
+    let index: usize = usize::BITS as usize;
+
+    // Set a bit.
+    if bitmap.set(index).is_err() {
+        panic!("failed to set bit at index {}", index);
+    }
+    match bitmap.set(index) {
+        Ok(_) => panic!("set bit that was already set"),
+        Err(e) if e.code == ErrorCode::ResourceBusy => {},
+        Err(e) => panic!("unexpected error code (error={:?})", e),
+    }
+    match bitmap.test(index) {
+        Ok(true) => {},
+        _ => panic!("bit at index {} was not set", index),
+    }
+
+    // Clear the bit.
+    if bitmap.clear(index).is_err() {
+        panic!("failed to clear bit at index {}", index);
+    }
+    match bitmap.clear(index) {
+        Ok(_) => panic!("cleared bit that was already cleared"),
+        Err(e) if e.code == ErrorCode::BadAddress => {},
+        Err(e) => panic!("unexpected error code (error={:?})", e),
+    }
+
+    // Access a bit out of bounds.
+    match bitmap.test(index + 1) {
+        Ok(_) => panic!("tested bit out of bounds"),
+        Err(e) if e.code == ErrorCode::InvalidArgument => {},
+        Err(e) => panic!("unexpected error code (error={:?})", e),
+    }
+}
+
+/// Attempts to allocate all bits in an [`AtomicBitmap`] from multiple threads concurrently.
+#[test]
+fn test_concurrent_alloc_and_clear() {
+    const LENGTH: usize = NUM_THREADS * BITS_PER_THREAD;
+    let bitmap: AtomicBitmap = match AtomicBitmap::new(LENGTH) {
+        Ok(bitmap) => bitmap,
+        Err(_) => panic!("failed to create bitmap"),
+    };
+
+    // Allocate all bits concurrently.
+    let mut allocated: Vec<usize> = thread::scope(|scope| {
+        let mut handles = Vec::new();
+        for _ in 0..NUM_THREADS {
+            handles.push(scope.spawn(|| {
+                let mut indexes: Vec<usize> = Vec::with_capacity(BITS_PER_THREAD);
+                for _ in 0..BITS_PER_THREAD {
+                    match bitmap.alloc() {
+                        Ok(index) => indexes.push(index),
+                        Err(e) => panic!("failed to allocate bit (error={:?})", e),
+                    }
+                }
+                indexes
+            }));
+        }
+
+        let mut allocated: Vec<usize> = Vec::with_capacity(LENGTH);
+        for handle in handles {
+            match handle.join() {
+                Ok(indexes) => allocated.extend(indexes),
+                Err(_) => panic!("thread panicked"),
+            }
+        }
+        allocated
+    });
+
+    // Check if every bit was handed out exactly once.
+    allocated.sort_unstable();
+    if !allocated.iter().copied().eq(0..LENGTH) {
+        panic!("bits were not handed out exactly once");
+    }
+    if bitmap.count_ones() != LENGTH {
+        panic!("unexpected usage (expected={}, got={})", LENGTH, bitmap.count_ones());
+    }
+    if bitmap.alloc().is_ok() {
+        panic!("allocated bit in a full bitmap");
+    }
+
+    // Clear and reallocate bits concurrently, each thread working on its own bits.
+    thread::scope(|scope| {
+        for chunk in allocated.chunks(BITS_PER_THREAD) {
+            let bitmap: &AtomicBitmap = &bitmap;
+            scope.spawn(move || {
+                for index in chunk {
+                    if let Err(e) = bitmap.clear(*index) {
+                        panic!("failed to clear bit at index {} (error={:?})", index, e);
+                    }
+                    if let Err(e) = bitmap.alloc() {
+                        panic!("failed to allocate bit (error={:?})", e);
+                    }
+                }
+            });
+        }
+    });
+
+    // Check if the bitmap is still full.
+    if bitmap.count_ones() != LENGTH || bitmap.alloc().is_ok() {
+        panic!("unexpected usage after concurrent clear and allocation");
+    }
+}
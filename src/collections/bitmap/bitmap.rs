@@ -11,6 +11,7 @@ use crate::{
         Error,
         ErrorCode,
     },
+    mm::Alignment,
 };
 
 //==================================================================================================
@@ -22,14 +23,34 @@ use crate::{
 ///
 /// A bitmap.
 ///
+/// Bits are stored in machine words, and bits that lie past the end of the bitmap in the last word
+/// are always kept cleared.
+///
 #[derive(Debug)]
 pub struct Bitmap {
     /// Capacity of the bitmap (in bits).
     number_of_bits: usize,
     /// Number of bits set in the bitmap.
     usage: usize,
+    /// Index of the first word that may have a cleared bit. All words before it are full.
+    next_free: usize,
     /// Underlying bits.
-    bits: RawArray<u8>,
+    bits: RawArray<usize>,
+}
+
+///
+/// # Description
+///
+/// An iterator over the indexes of bits that have a given value in a [`Bitmap`].
+///
+#[derive(Debug)]
+pub struct BitmapIter<'a> {
+    /// Bitmap that is iterated over.
+    bitmap: &'a Bitmap,
+    /// Index of the next bit to inspect.
+    next: usize,
+    /// Value of the bits to look for.
+    value: bool,
 }
 
 //==================================================================================================
@@ -37,6 +58,9 @@ pub struct Bitmap {
 //==================================================================================================
 
 impl Bitmap {
+    /// Number of bits in a word of the bitmap.
+    pub(crate) const WORD_BITS: usize = usize::BITS as usize;
+
     ///
     /// # Description
     ///
@@ -57,47 +81,60 @@ impl Bitmap {
         }
 
         // Allocate the bitmap.
-        let mut array: RawArray<u8> = RawArray::new(len)?;
-
-        // Zero out the bitmap.
-        for byte in array.iter_mut() {
-            *byte = 0;
-        }
+        let array: RawArray<usize> = RawArray::new(Self::words_for(len))?;
 
-        Ok(Self {
-            number_of_bits: array.len() * u8::BITS as usize,
-            bits: array,
-            usage: 0,
-        })
+        Self::from_raw_array(array, len)
     }
 
     ///
     /// # Description
     ///
-    /// Creates a new bitmap from a raw array. The bitmap is initialized with
-    /// all bits set to zero.
+    /// Creates a new bitmap from a raw array of words. The bitmap is initialized with all bits set
+    /// to zero.
     ///
     /// # Parameters
     ///
     /// - `array`: Raw array to create the bitmap from.
+    /// - `len`: Length of the bitmap in bits.
     ///
     /// # Returns
     ///
     /// Upon success, a new bitmap is returned. Upon failure, an error is returned instead.
     ///
-    pub fn from_raw_array(mut array: RawArray<u8>) -> Self {
-        // NOTE: no need to test if the length of the raw array is valid, as it is by construction.
+    pub fn from_raw_array(mut array: RawArray<usize>, len: usize) -> Result<Self, Error> {
+        // Check if the length is invalid.
+        if len == 0 || len > array.len() * Self::WORD_BITS {
+            return Err(Error::new(ErrorCode::InvalidArgument, "invalid length"));
+        }
 
         // Zero out the bitmap.
-        for byte in array.iter_mut() {
-            *byte = 0;
+        for word in array.iter_mut() {
+            *word = 0;
         }
 
-        Self {
-            number_of_bits: array.len() * u8::BITS as usize,
-            bits: array,
+        Ok(Self {
+            number_of_bits: len,
             usage: 0,
-        }
+            next_free: 0,
+            bits: array,
+        })
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the number of words that are required to store a given number of bits.
+    ///
+    /// # Parameters
+    ///
+    /// - `len`: Number of bits.
+    ///
+    /// # Returns
+    ///
+    /// The number of words that are required to store `len` bits.
+    ///
+    pub const fn words_for(len: usize) -> usize {
+        len.div_ceil(Self::WORD_BITS)
     }
 
     ///
@@ -116,7 +153,127 @@ impl Bitmap {
     ///
     /// # Description
     ///
-    /// Allocates a bit in the bitmap.
+    /// Returns the number of bits that are set in the bitmap.
+    ///
+    /// # Returns
+    ///
+    /// The number of bits that are set in the bitmap.
+    ///
+    pub fn count_ones(&self) -> usize {
+        self.usage
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the number of bits that are cleared in the bitmap.
+    ///
+    /// # Returns
+    ///
+    /// The number of bits that are cleared in the bitmap.
+    ///
+    pub fn count_zeros(&self) -> usize {
+        self.number_of_bits - self.usage
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Checks if all bits in the bitmap are set.
+    ///
+    /// # Returns
+    ///
+    /// `true` if all bits in the bitmap are set, `false` otherwise.
+    ///
+    pub fn is_full(&self) -> bool {
+        self.usage == self.number_of_bits
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Checks if all bits in the bitmap are cleared.
+    ///
+    /// # Returns
+    ///
+    /// `true` if all bits in the bitmap are cleared, `false` otherwise.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.usage == 0
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Finds the next bit that is set in the bitmap, starting at a given index.
+    ///
+    /// # Parameters
+    ///
+    /// - `from`: Index of the first bit to inspect.
+    ///
+    /// # Returns
+    ///
+    /// The index of the next bit that is set, if any.
+    ///
+    pub fn find_next_set(&self, from: usize) -> Option<usize> {
+        self.find_next(from, self.number_of_bits, true)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Finds the next bit that is cleared in the bitmap, starting at a given index.
+    ///
+    /// # Parameters
+    ///
+    /// - `from`: Index of the first bit to inspect.
+    ///
+    /// # Returns
+    ///
+    /// The index of the next bit that is cleared, if any.
+    ///
+    pub fn find_next_clear(&self, from: usize) -> Option<usize> {
+        self.find_next(from, self.number_of_bits, false)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns an iterator over the indexes of bits that are set in the bitmap.
+    ///
+    /// # Returns
+    ///
+    /// An iterator over the indexes of bits that are set in the bitmap, in ascending order.
+    ///
+    pub fn iter_set(&self) -> BitmapIter<'_> {
+        BitmapIter {
+            bitmap: self,
+            next: 0,
+            value: true,
+        }
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns an iterator over the indexes of bits that are cleared in the bitmap.
+    ///
+    /// # Returns
+    ///
+    /// An iterator over the indexes of bits that are cleared in the bitmap, in ascending order.
+    ///
+    pub fn iter_clear(&self) -> BitmapIter<'_> {
+        BitmapIter {
+            bitmap: self,
+            next: 0,
+            value: false,
+        }
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Allocates a bit in the bitmap. The lowest cleared bit is always the one that is allocated.
     ///
     /// # Returns
     ///
@@ -124,32 +281,39 @@ impl Bitmap {
     /// instead.
     ///
     pub fn alloc(&mut self) -> Result<usize, Error> {
-        // TODO: optimize this implementation to traverse the bitmap in a machine word at a time.
+        // Traverse the bitmap one word at a time, skipping words that are known to be full.
+        for i in self.next_free..self.bits.len() {
+            let word: usize = self.bits[i];
 
-        // Traverse the bitmap one word at a time.
-        for (i, word) in self.bits.iter_mut().enumerate() {
             // Check if this word is not full.
-            if *word != u8::MAX {
-                // Find a free bit.
-                for j in 0..u8::BITS as usize {
-                    // Check if the bit is free.
-                    if *word & (1 << j) == 0 {
-                        // It is, thus allocate it.
-                        *word |= 1 << j;
-                        self.usage += 1;
-                        return Ok(i * u8::BITS as usize + j);
-                    }
+            if word != usize::MAX {
+                // Find the lowest free bit.
+                let bit: usize = word.trailing_ones() as usize;
+                let index: usize = i * Self::WORD_BITS + bit;
+
+                // Check if the free bit lies past the end of the bitmap.
+                if index >= self.number_of_bits {
+                    break;
                 }
+
+                // Allocate the bit.
+                self.bits[i] |= 1 << bit;
+                self.usage += 1;
+                self.next_free = i;
+                return Ok(index);
             }
         }
 
+        // All words are full.
+        self.next_free = self.bits.len();
+
         Err(Error::new(ErrorCode::OutOfMemory, "bitmap is full"))
     }
 
     ///
     /// # Description
     ///
-    /// Allocates a range of bits in the bitmap.
+    /// Allocates a range of contiguous bits in the bitmap. The range may span multiple words.
     ///
     /// # Parameters
     ///
@@ -161,42 +325,105 @@ impl Bitmap {
     /// instead.
     ///
     pub fn alloc_range(&mut self, size: usize) -> Result<usize, Error> {
-        // Check if the size is invalid.
-        if (size == 0) || (size > u8::BITS as usize) {
-            return Err(Error::new(ErrorCode::InvalidArgument, "invalid size"));
-        }
+        self.do_alloc_range(size, 1)
+    }
 
-        // Check if the size is out of bounds.
-        if size > self.number_of_bits {
-            return Err(Error::new(ErrorCode::InvalidArgument, "size out of bounds"));
+    ///
+    /// # Description
+    ///
+    /// Allocates a range of contiguous bits in the bitmap, such that the index of the first bit in
+    /// the range is aligned to `align`.
+    ///
+    /// # Parameters
+    ///
+    /// - `size`: Size of the range to allocate.
+    /// - `align`: Alignment of the index of the first bit in the range.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, the index of the allocated range is returned. Upon failure, an error is returned
+    /// instead.
+    ///
+    pub fn alloc_range_aligned(&mut self, size: usize, align: Alignment) -> Result<usize, Error> {
+        self.do_alloc_range(size, align as usize)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Frees a range of contiguous bits in the bitmap.
+    ///
+    /// # Parameters
+    ///
+    /// - `start`: Index of the first bit in the range.
+    /// - `size`: Size of the range to free.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, `Ok(())` is returned. Upon failure, an error is returned instead and the bitmap
+    /// is left unmodified.
+    ///
+    pub fn free_range(&mut self, start: usize, size: usize) -> Result<(), Error> {
+        self.clear_range(start, size)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Sets a range of contiguous bits in the bitmap.
+    ///
+    /// # Parameters
+    ///
+    /// - `start`: Index of the first bit in the range.
+    /// - `size`: Size of the range to set.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, `Ok(())` is returned. Upon failure, an error is returned instead and the bitmap
+    /// is left unmodified.
+    ///
+    pub fn set_range(&mut self, start: usize, size: usize) -> Result<(), Error> {
+        let end: usize = self.range(start, size)?;
+
+        // Check if any bit in the range is already set.
+        if self.find_next(start, end, true).is_some() {
+            return Err(Error::new(ErrorCode::ResourceBusy, "bit is already set"));
         }
 
-        // Compute mask.
-        let mask: u8 = if size == u8::BITS as usize {
-            // Use u8::MAX directly to avoid overflow
-            u8::MAX
-        } else {
-            ((1 << size) - 1) as u8
-        };
+        self.fill(start, end, true);
+        self.usage += size;
 
-        // Traverse the bitmap one word at a time.
-        for (i, word) in self.bits.iter_mut().enumerate() {
-            // Check if this word is not full.
-            if *word != u8::MAX {
-                // Find a free range of bits.
-                for j in 0..=(u8::BITS as usize - size) {
-                    // Check if the range is free.
-                    if (*word & (mask << j)) == 0 {
-                        // It is, thus allocate it.
-                        *word |= mask << j;
-                        self.usage += size;
-                        return Ok(i * u8::BITS as usize + j);
-                    }
-                }
-            }
+        Ok(())
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Clears a range of contiguous bits in the bitmap.
+    ///
+    /// # Parameters
+    ///
+    /// - `start`: Index of the first bit in the range.
+    /// - `size`: Size of the range to clear.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, `Ok(())` is returned. Upon failure, an error is returned instead and the bitmap
+    /// is left unmodified.
+    ///
+    pub fn clear_range(&mut self, start: usize, size: usize) -> Result<(), Error> {
+        let end: usize = self.range(start, size)?;
+
+        // Check if any bit in the range is already cleared.
+        if self.find_next(start, end, false).is_some() {
+            return Err(Error::new(ErrorCode::BadAddress, "bit is already cleared"));
         }
 
-        Err(Error::new(ErrorCode::OutOfMemory, "bitmap is full"))
+        self.fill(start, end, false);
+        self.usage -= size;
+        self.next_free = self.next_free.min(start / Self::WORD_BITS);
+
+        Ok(())
     }
 
     ///
@@ -244,6 +471,7 @@ impl Bitmap {
         let (word, bit): (usize, usize) = self.index(index)?;
         self.bits[word] &= !(1 << bit);
         self.usage -= 1;
+        self.next_free = self.next_free.min(word);
         Ok(())
     }
 
@@ -266,6 +494,502 @@ impl Bitmap {
         Ok((self.bits[word] & (1 << bit)) != 0)
     }
 
+    ///
+    /// # Description
+    ///
+    /// Creates a copy of the bitmap, backed by managed storage.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, a copy of the bitmap is returned. Upon failure, an error is returned instead.
+    ///
+    pub fn try_clone(&self) -> Result<Bitmap, Error> {
+        let mut bitmap: Bitmap = Bitmap::new(self.number_of_bits)?;
+        bitmap.bits.copy_from_slice(&self.bits);
+        bitmap.usage = self.usage;
+        bitmap.next_free = self.next_free;
+        Ok(bitmap)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Sets all bits in the bitmap that are set in another bitmap.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: Bitmap to merge into the target bitmap.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, `Ok(())` is returned. Upon failure, an error is returned instead.
+    ///
+    pub fn union_with(&mut self, other: &Bitmap) -> Result<(), Error> {
+        self.combine(other, |a, b| a | b)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Clears all bits in the bitmap that are cleared in another bitmap.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: Bitmap to intersect with the target bitmap.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, `Ok(())` is returned. Upon failure, an error is returned instead.
+    ///
+    pub fn intersect_with(&mut self, other: &Bitmap) -> Result<(), Error> {
+        self.combine(other, |a, b| a & b)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Clears all bits in the bitmap that are set in another bitmap.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: Bitmap to subtract from the target bitmap.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, `Ok(())` is returned. Upon failure, an error is returned instead.
+    ///
+    pub fn difference_with(&mut self, other: &Bitmap) -> Result<(), Error> {
+        self.combine(other, |a, b| a & !b)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Toggles all bits in the bitmap that are set in another bitmap.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: Bitmap to exclusive-or with the target bitmap.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, `Ok(())` is returned. Upon failure, an error is returned instead.
+    ///
+    pub fn xor_with(&mut self, other: &Bitmap) -> Result<(), Error> {
+        self.combine(other, |a, b| a ^ b)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Toggles all bits in the bitmap.
+    ///
+    pub fn invert(&mut self) {
+        for word in self.bits.iter_mut() {
+            *word = !*word;
+        }
+        self.refresh();
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Computes the union of the bitmap and another bitmap.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: Other bitmap.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, a new bitmap is returned. Upon failure, an error is returned instead.
+    ///
+    pub fn union(&self, other: &Bitmap) -> Result<Bitmap, Error> {
+        self.check_length(other)?;
+        let mut bitmap: Bitmap = self.try_clone()?;
+        bitmap.union_with(other)?;
+        Ok(bitmap)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Computes the intersection of the bitmap and another bitmap.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: Other bitmap.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, a new bitmap is returned. Upon failure, an error is returned instead.
+    ///
+    pub fn intersect(&self, other: &Bitmap) -> Result<Bitmap, Error> {
+        self.check_length(other)?;
+        let mut bitmap: Bitmap = self.try_clone()?;
+        bitmap.intersect_with(other)?;
+        Ok(bitmap)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Computes the difference between the bitmap and another bitmap.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: Other bitmap.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, a new bitmap is returned. Upon failure, an error is returned instead.
+    ///
+    pub fn difference(&self, other: &Bitmap) -> Result<Bitmap, Error> {
+        self.check_length(other)?;
+        let mut bitmap: Bitmap = self.try_clone()?;
+        bitmap.difference_with(other)?;
+        Ok(bitmap)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Computes the symmetric difference between the bitmap and another bitmap.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: Other bitmap.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, a new bitmap is returned. Upon failure, an error is returned instead.
+    ///
+    pub fn xor(&self, other: &Bitmap) -> Result<Bitmap, Error> {
+        self.check_length(other)?;
+        let mut bitmap: Bitmap = self.try_clone()?;
+        bitmap.xor_with(other)?;
+        Ok(bitmap)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Computes the complement of the bitmap.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, a new bitmap is returned. Upon failure, an error is returned instead.
+    ///
+    pub fn complement(&self) -> Result<Bitmap, Error> {
+        let mut bitmap: Bitmap = self.try_clone()?;
+        bitmap.invert();
+        Ok(bitmap)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Checks if all bits that are set in the bitmap are also set in another bitmap.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: Other bitmap.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, `Ok(true)` is returned if the bitmap is a subset of `other`, `Ok(false)` is
+    /// returned otherwise. Upon failure, an error is returned instead.
+    ///
+    pub fn is_subset_of(&self, other: &Bitmap) -> Result<bool, Error> {
+        self.check_length(other)?;
+
+        // Check if any bit set in the target bitmap is cleared in the other bitmap.
+        for (a, b) in self.bits.iter().zip(other.bits.iter()) {
+            if a & !b != 0 {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Checks if all bits of a given word of the bitmap are set. Bits past the end of the bitmap are
+    /// not taken into account.
+    ///
+    /// # Parameters
+    ///
+    /// - `word`: Index of the word.
+    ///
+    /// # Returns
+    ///
+    /// `true` if all bits of the word are set, `false` otherwise.
+    ///
+    pub(crate) fn is_word_full(&self, word: usize) -> bool {
+        let len: usize = (self.number_of_bits - word * Self::WORD_BITS).min(Self::WORD_BITS);
+        let mask: usize = if len == Self::WORD_BITS {
+            usize::MAX
+        } else {
+            (1 << len) - 1
+        };
+        self.bits[word] & mask == mask
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Allocates a range of contiguous bits in the bitmap, using a first-fit strategy.
+    ///
+    /// # Parameters
+    ///
+    /// - `size`: Size of the range to allocate.
+    /// - `align`: Alignment of the index of the first bit in the range (power of two).
+    ///
+    /// # Returns
+    ///
+    /// Upon success, the index of the allocated range is returned. Upon failure, an error is returned
+    /// instead.
+    ///
+    fn do_alloc_range(&mut self, size: usize, align: usize) -> Result<usize, Error> {
+        // Check if the size is invalid.
+        if size == 0 {
+            return Err(Error::new(ErrorCode::InvalidArgument, "invalid size"));
+        }
+
+        // Check if the size is out of bounds.
+        if size > self.number_of_bits {
+            return Err(Error::new(ErrorCode::InvalidArgument, "size out of bounds"));
+        }
+
+        // Words before the hint are full, so the search can start right after them.
+        let mut start: usize = self.next_free * Self::WORD_BITS;
+        loop {
+            // Skip to the next cleared bit that is suitably aligned.
+            start = match self.find_next(start, self.number_of_bits, false) {
+                Some(index) => (index + align - 1) & !(align - 1),
+                None => break,
+            };
+
+            // Check if the range lies past the end of the bitmap.
+            let end: usize = start + size;
+            if end > self.number_of_bits {
+                break;
+            }
+
+            // Check if the range is free.
+            match self.find_next(start, end, true) {
+                // It is not, thus skip past the bit that is set.
+                Some(index) => start = index + 1,
+                // It is, thus allocate it.
+                None => {
+                    self.fill(start, end, true);
+                    self.usage += size;
+                    return Ok(start);
+                },
+            }
+        }
+
+        Err(Error::new(ErrorCode::OutOfMemory, "bitmap is full"))
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Combines the bitmap with another bitmap, one word at a time.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: Other bitmap.
+    /// - `op`: Operation that combines a word of the target bitmap with a word of `other`.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, `Ok(())` is returned. Upon failure, an error is returned instead and the bitmap
+    /// is left unmodified.
+    ///
+    fn combine(&mut self, other: &Bitmap, op: impl Fn(usize, usize) -> usize) -> Result<(), Error> {
+        self.check_length(other)?;
+
+        for (a, b) in self.bits.iter_mut().zip(other.bits.iter()) {
+            *a = op(*a, *b);
+        }
+        self.refresh();
+
+        Ok(())
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Checks if the bitmap has the same length as another bitmap.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: Other bitmap.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, `Ok(())` is returned. Upon failure, an error is returned instead.
+    ///
+    fn check_length(&self, other: &Bitmap) -> Result<(), Error> {
+        if self.number_of_bits != other.number_of_bits {
+            return Err(Error::new(ErrorCode::InvalidArgument, "bitmap length mismatch"));
+        }
+        Ok(())
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Restores invariants of the bitmap after its words have been modified in bulk. Bits past the
+    /// end of the bitmap are cleared, and both usage and next-free hint are recomputed.
+    ///
+    fn refresh(&mut self) {
+        // Clear bits past the end of the bitmap.
+        let tail: usize = self.number_of_bits % Self::WORD_BITS;
+        if tail != 0 {
+            let last: usize = self.bits.len() - 1;
+            self.bits[last] &= (1 << tail) - 1;
+        }
+
+        // Recompute usage and next-free hint.
+        self.usage = 0;
+        self.next_free = self.bits.len();
+        for (i, word) in self.bits.iter().enumerate() {
+            self.usage += word.count_ones() as usize;
+            if *word != usize::MAX {
+                self.next_free = self.next_free.min(i);
+            }
+        }
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Finds the next bit that has a given value, in the range `[from, end)`.
+    ///
+    /// # Parameters
+    ///
+    /// - `from`: Index of the first bit to inspect.
+    /// - `end`: Index past the last bit to inspect. It should not exceed the length of the bitmap.
+    /// - `value`: Value of the bit to look for.
+    ///
+    /// # Returns
+    ///
+    /// The index of the bit found, if any.
+    ///
+    fn find_next(&self, from: usize, end: usize, value: bool) -> Option<usize> {
+        if from >= end {
+            return None;
+        }
+
+        // Load the first word, ignoring bits before the starting index.
+        let mut word: usize = from / Self::WORD_BITS;
+        let mut bits: usize = self.load(word, value) & (usize::MAX << (from % Self::WORD_BITS));
+
+        loop {
+            // Check if this word has a matching bit.
+            if bits != 0 {
+                let index: usize = word * Self::WORD_BITS + bits.trailing_zeros() as usize;
+                return if index < end { Some(index) } else { None };
+            }
+
+            word += 1;
+            if word * Self::WORD_BITS >= end {
+                return None;
+            }
+            bits = self.load(word, value);
+        }
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Loads a word of the bitmap, such that bits that have a given value read as ones.
+    ///
+    /// # Parameters
+    ///
+    /// - `word`: Index of the word.
+    /// - `value`: Value of the bits that should read as ones.
+    ///
+    /// # Returns
+    ///
+    /// The word at index `word`, possibly inverted.
+    ///
+    fn load(&self, word: usize, value: bool) -> usize {
+        if value {
+            self.bits[word]
+        } else {
+            !self.bits[word]
+        }
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Sets or clears all bits in the range `[start, end)`, one word at a time. This function does
+    /// not update usage statistics.
+    ///
+    /// # Parameters
+    ///
+    /// - `start`: Index of the first bit in the range.
+    /// - `end`: Index past the last bit in the range.
+    /// - `value`: Value to which bits should be set.
+    ///
+    fn fill(&mut self, start: usize, end: usize, value: bool) {
+        let mut index: usize = start;
+        while index < end {
+            let word: usize = index / Self::WORD_BITS;
+            let lo: usize = index % Self::WORD_BITS;
+            let hi: usize = (end - word * Self::WORD_BITS).min(Self::WORD_BITS);
+
+            // Compute mask for bits `[lo, hi)` of the word.
+            let mask: usize = if hi == Self::WORD_BITS {
+                usize::MAX << lo
+            } else {
+                ((1 << hi) - 1) & (usize::MAX << lo)
+            };
+
+            if value {
+                self.bits[word] |= mask;
+            } else {
+                self.bits[word] &= !mask;
+            }
+
+            index = word * Self::WORD_BITS + hi;
+        }
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Checks if a range of bits lies within the bitmap.
+    ///
+    /// # Parameters
+    ///
+    /// - `start`: Index of the first bit in the range.
+    /// - `size`: Size of the range.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, the index past the last bit in the range is returned. Upon failure, an error
+    /// is returned instead.
+    ///
+    fn range(&self, start: usize, size: usize) -> Result<usize, Error> {
+        // Check if the size is invalid.
+        if size == 0 {
+            return Err(Error::new(ErrorCode::InvalidArgument, "invalid size"));
+        }
+
+        // Check if the range is out of bounds.
+        match start.checked_add(size) {
+            Some(end) if end <= self.number_of_bits => Ok(end),
+            _ => Err(Error::new(ErrorCode::InvalidArgument, "range out of bounds")),
+        }
+    }
+
     ///
     /// # Description
     ///
@@ -282,20 +1006,40 @@ impl Bitmap {
     ///
     fn index(&self, index: usize) -> Result<(usize, usize), Error> {
         // Check if the index is out of bounds.
-        if index >= self.bits.len() * u8::BITS as usize {
+        if index >= self.number_of_bits {
             return Err(Error::new(ErrorCode::InvalidArgument, "index out of bounds"));
         }
 
-        let word: usize = index / u8::BITS as usize;
-        let bit: usize = index % u8::BITS as usize;
+        let word: usize = index / Self::WORD_BITS;
+        let bit: usize = index % Self::WORD_BITS;
 
         Ok((word, bit))
     }
 }
 
+impl PartialEq for Bitmap {
+    fn eq(&self, other: &Self) -> bool {
+        // NOTE: bits past the end of the bitmap are always cleared, thus words can be compared.
+        self.number_of_bits == other.number_of_bits && *self.bits == *other.bits
+    }
+}
+
+impl Eq for Bitmap {}
+
+impl Iterator for BitmapIter<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let end: usize = self.bitmap.number_of_bits;
+        let index: usize = self.bitmap.find_next(self.next, end, self.value)?;
+        self.next = index + 1;
+        Some(index)
+    }
+}
+
 #[cfg(test)]
 impl ::core::ops::Deref for Bitmap {
-    type Target = RawArray<u8>;
+    type Target = RawArray<usize>;
 
     fn deref(&self) -> &Self::Target {
         &self.bits
@@ -10,7 +10,11 @@ use crate::{
         raw_array::RawArray,
         Bitmap,
     },
-    error::Error,
+    error::{
+        Error,
+        ErrorCode,
+    },
+    mm::Alignment,
 };
 use ::core::panic;
 
@@ -19,21 +23,21 @@ use ::core::panic;
 //==================================================================================================
 
 /// Helper test function that creates a [`Bitmap`] from a raw array.
-fn test_helper_create_bitmap_from_raw_array(data: &mut [u8]) -> Result<Bitmap, Error> {
-    let ptr: *mut u8 = data.as_mut_ptr();
+fn test_helper_create_bitmap_from_raw_array(data: &mut [usize]) -> Result<Bitmap, Error> {
+    let ptr: *mut usize = data.as_mut_ptr();
     let len: usize = data.len();
     let array = match unsafe { RawArray::from_raw_parts(ptr, len) } {
         Ok(array) => array,
         Err(e) => return Err(e),
     };
 
-    Ok(Bitmap::from_raw_array(array))
+    Bitmap::from_raw_array(array, len * usize::BITS as usize)
 }
 
 /// Attempts to create a [`Bitmap`] from a raw array.
 #[test]
 fn test_from_raw_array() {
-    let mut data: [u8; 4] = [1; 4];
+    let mut data: [usize; 4] = [1; 4];
 
     // Create bitmap.
     let bitmap: Bitmap = match test_helper_create_bitmap_from_raw_array(&mut data) {
@@ -42,14 +46,14 @@ fn test_from_raw_array() {
     };
 
     // Check if the bitmap has the expected length.
-    if bitmap.number_of_bits() != data.len() * u8::BITS as usize {
+    if bitmap.number_of_bits() != data.len() * usize::BITS as usize {
         panic!("unexpected length (expected={}, got={})", data.len(), bitmap.number_of_bits());
     }
 
     // Check if the bitmap was initialized if all bits set to zero.
-    for byte in bitmap.iter() {
-        if *byte != 0 {
-            panic!("unexpected byte value (expected=0, got={})", *byte);
+    for word in bitmap.iter() {
+        if *word != 0 {
+            panic!("unexpected word value (expected=0, got={})", *word);
         }
     }
 }
@@ -57,7 +61,7 @@ fn test_from_raw_array() {
 /// Attempts to set and clear all bits in a [`Bitmap`].
 #[test]
 fn test_set_and_clear_all_bits() {
-    let mut data: [u8; 4] = [0; 4];
+    let mut data: [usize; 4] = [0; 4];
 
     // Create bitmap.
     let mut bitmap: Bitmap = match test_helper_create_bitmap_from_raw_array(&mut data) {
@@ -75,9 +79,9 @@ fn test_set_and_clear_all_bits() {
     }
 
     // Check if all bits were set.
-    for byte in bitmap.iter() {
-        if *byte != u8::MAX {
-            panic!("unexpected byte value (expected={}, got={})", u8::MAX, *byte);
+    for word in bitmap.iter() {
+        if *word != usize::MAX {
+            panic!("unexpected word value (expected={}, got={})", usize::MAX, *word);
         }
     }
 
@@ -89,9 +93,9 @@ fn test_set_and_clear_all_bits() {
     }
 
     // Check if all bits were cleared.
-    for byte in bitmap.iter() {
-        if *byte != 0 {
-            panic!("unexpected byte value (expected=0, got={})", *byte);
+    for word in bitmap.iter() {
+        if *word != 0 {
+            panic!("unexpected word value (expected=0, got={})", *word);
         }
     }
 }
@@ -99,7 +103,7 @@ fn test_set_and_clear_all_bits() {
 /// Attempts to allocate an clear all bits in a [`Bitmap`].
 #[test]
 fn test_alloc_and_clear_all_bits() {
-    let mut data: [u8; 4] = [0; 4];
+    let mut data: [usize; 4] = [0; 4];
 
     // Create bitmap.
     let mut bitmap: Bitmap = match test_helper_create_bitmap_from_raw_array(&mut data) {
@@ -117,9 +121,9 @@ fn test_alloc_and_clear_all_bits() {
     }
 
     // Check if all bits were allocated.
-    for byte in bitmap.iter() {
-        if *byte != u8::MAX {
-            panic!("unexpected byte value (expected={}, got={})", u8::MAX, *byte);
+    for word in bitmap.iter() {
+        if *word != usize::MAX {
+            panic!("unexpected word value (expected={}, got={})", usize::MAX, *word);
         }
     }
 
@@ -131,9 +135,407 @@ fn test_alloc_and_clear_all_bits() {
     }
 
     // Check if all bits were cleared.
-    for byte in bitmap.iter() {
-        if *byte != 0 {
-            panic!("unexpected byte value (expected=0, got={})", *byte);
+    for word in bitmap.iter() {
+        if *word != 0 {
+            panic!("unexpected word value (expected=0, got={})", *word);
+        }
+    }
+}
+
+/// Attempts to allocate a bit after clearing a bit in a fully allocated [`Bitmap`].
+#[test]
+fn test_alloc_after_clear() {
+    let mut data: [usize; 4] = [0; 4];
+
+    // Create bitmap.
+    let mut bitmap: Bitmap = match test_helper_create_bitmap_from_raw_array(&mut data) {
+        Ok(bitmap) => bitmap,
+        Err(_) => {
+            panic!("failed to create bitmap");
+        },
+    };
+
+    // Allocate all bits.
+    for i in 0..bitmap.number_of_bits() {
+        match bitmap.alloc() {
+            Ok(index) if index == i => {},
+            Ok(index) => panic!("unexpected index (expected={}, got={})", i, index),
+            Err(_) => panic!("failed to allocate bit at index {}", i),
+        }
+    }
+
+    // Check if the bitmap is full.
+    if bitmap.alloc().is_ok() {
+        panic!("allocated bit in a full bitmap");
+    }
+
+    // Clear a bit and allocate it again.
+    let index: usize = bitmap.number_of_bits() / 2 + 1;
+    if bitmap.clear(index).is_err() {
+        panic!("failed to clear bit at index {}", index);
+    }
+    match bitmap.alloc() {
+        Ok(i) if i == index => {},
+        Ok(i) => panic!("unexpected index (expected={}, got={})", index, i),
+        Err(_) => panic!("failed to allocate bit at index {}", index),
+    }
+}
+
+/// Attempts to allocate all bits in a [`Bitmap`] whose length is not a multiple of the word size.
+#[test]
+fn test_alloc_partial_word() {
+    const LENGTH: usize = usize::BITS as usize + 3;
+
+    // Create bitmap.
+    let mut bitmap: Bitmap = match Bitmap::new(LENGTH) {
+        Ok(bitmap) => bitmap,
+        Err(_) => panic!("failed to create bitmap"),
+    };
+
+    // Check if the bitmap has the expected length.
+    if bitmap.number_of_bits() != LENGTH {
+        panic!("unexpected length (expected={}, got={})", LENGTH, bitmap.number_of_bits());
+    }
+
+    // Allocate all bits.
+    for i in 0..LENGTH {
+        if bitmap.alloc().is_err() {
+            panic!("failed to allocate bit at index {}", i);
+        }
+    }
+
+    // Check if bits past the end of the bitmap are not handed out.
+    if bitmap.alloc().is_ok() {
+        panic!("allocated bit past the end of the bitmap");
+    }
+}
+
+/// Attempts to allocate and free a range of bits that spans multiple words in a [`Bitmap`].
+#[test]
+fn test_alloc_and_free_range_across_words() {
+    const LENGTH: usize = 4 * usize::BITS as usize;
+    let size: usize = 2 * usize::BITS as usize;
+
+    // Create bitmap.
+    let mut bitmap: Bitmap = match Bitmap::new(LENGTH) {
+        Ok(bitmap) => bitmap,
+        Err(_) => panic!("failed to create bitmap"),
+    };
+
+    // Set a bit in the first word, so that the range cannot start at index zero.
+    if bitmap.set(3).is_err() {
+        panic!("failed to set bit at index 3");
+    }
+
+    // Allocate range.
+    let start: usize = match bitmap.alloc_range(size) {
+        Ok(start) => start,
+        Err(_) => panic!("failed to allocate range of size {}", size),
+    };
+    if start != 4 {
+        panic!("unexpected range start (expected=4, got={})", start);
+    }
+
+    // Check if all bits in the range were set.
+    for i in start..(start + size) {
+        match bitmap.test(i) {
+            Ok(true) => {},
+            _ => panic!("bit at index {} was not set", i),
         }
     }
+
+    // Check if bits surrounding the range were left untouched.
+    match (bitmap.test(start - 1), bitmap.test(start + size)) {
+        (Ok(true), Ok(false)) => {},
+        _ => panic!("bits surrounding the range were modified"),
+    }
+
+    // Free range.
+    if bitmap.free_range(start, size).is_err() {
+        panic!("failed to free range at index {}", start);
+    }
+
+    // Check if all bits in the range were cleared.
+    for i in start..(start + size) {
+        match bitmap.test(i) {
+            Ok(false) => {},
+            _ => panic!("bit at index {} was not cleared", i),
+        }
+    }
+
+    // Attempt to free the range again.
+    if bitmap.free_range(start, size).is_ok() {
+        panic!("freed range that was already free");
+    }
+}
+
+/// Attempts to allocate a range of bits that does not fit in a [`Bitmap`].
+#[test]
+fn test_alloc_range_no_fit() {
+    const LENGTH: usize = 2 * usize::BITS as usize;
+
+    // Create bitmap.
+    let mut bitmap: Bitmap = match Bitmap::new(LENGTH) {
+        Ok(bitmap) => bitmap,
+        Err(_) => panic!("failed to create bitmap"),
+    };
+
+    // Split the bitmap in two halves.
+    if bitmap.set(LENGTH / 2).is_err() {
+        panic!("failed to set bit at index {}", LENGTH / 2);
+    }
+
+    // Attempt to allocate a range that is larger than any free range.
+    match bitmap.alloc_range(LENGTH / 2 + 1) {
+        Ok(_) => panic!("allocated range that does not fit"),
+        Err(e) if e.code == ErrorCode::OutOfMemory => {},
+        Err(e) => panic!("unexpected error code (error={:?})", e),
+    }
+
+    // Attempt to allocate a range that is larger than the bitmap.
+    match bitmap.alloc_range(LENGTH + 1) {
+        Ok(_) => panic!("allocated range larger than the bitmap"),
+        Err(e) if e.code == ErrorCode::InvalidArgument => {},
+        Err(e) => panic!("unexpected error code (error={:?})", e),
+    }
+}
+
+/// Attempts to allocate aligned ranges of bits in a [`Bitmap`].
+#[test]
+fn test_alloc_range_aligned() {
+    const LENGTH: usize = 256;
+
+    // Create bitmap.
+    let mut bitmap: Bitmap = match Bitmap::new(LENGTH) {
+        Ok(bitmap) => bitmap,
+        Err(_) => panic!("failed to create bitmap"),
+    };
+
+    // Set a bit so that the first aligned range cannot start at index zero.
+    if bitmap.set(1).is_err() {
+        panic!("failed to set bit at index 1");
+    }
+
+    // Allocate aligned ranges.
+    for expected in [64, 128, 192] {
+        match bitmap.alloc_range_aligned(48, Alignment::Align64) {
+            Ok(start) if start == expected => {},
+            Ok(start) => panic!("unexpected range start (expected={}, got={})", expected, start),
+            Err(_) => panic!("failed to allocate aligned range"),
+        }
+    }
+
+    // Check if the bitmap has no room left for aligned ranges.
+    if bitmap.alloc_range_aligned(48, Alignment::Align64).is_ok() {
+        panic!("allocated aligned range in a bitmap with no room left");
+    }
+
+    // Check if unaligned ranges can still be allocated.
+    match bitmap.alloc_range(48) {
+        Ok(2) => {},
+        Ok(start) => panic!("unexpected range start (expected=2, got={})", start),
+        Err(_) => panic!("failed to allocate unaligned range"),
+    }
+}
+
+/// Attempts to query the usage of a [`Bitmap`].
+#[test]
+fn test_count_and_usage() {
+    const LENGTH: usize = usize::BITS as usize + 5;
+
+    // Create bitmap.
+    let mut bitmap: Bitmap = match Bitmap::new(LENGTH) {
+        Ok(bitmap) => bitmap,
+        Err(_) => panic!("failed to create bitmap"),
+    };
+
+    // Check if the bitmap is empty.
+    if !bitmap.is_empty() || bitmap.is_full() || bitmap.count_zeros() != LENGTH {
+        panic!("unexpected usage of an empty bitmap");
+    }
+
+    // Set a range of bits.
+    if bitmap.set_range(2, 10).is_err() {
+        panic!("failed to set range");
+    }
+    if bitmap.count_ones() != 10 || bitmap.count_zeros() != LENGTH - 10 {
+        panic!("unexpected usage (expected=10, got={})", bitmap.count_ones());
+    }
+
+    // Attempt to set an overlapping range.
+    if bitmap.set_range(0, 3).is_ok() {
+        panic!("set range that overlaps with bits already set");
+    }
+    if bitmap.count_ones() != 10 {
+        panic!("failed set range modified the bitmap");
+    }
+
+    // Fill the bitmap.
+    if bitmap.set_range(0, 2).is_err() || bitmap.set_range(12, LENGTH - 12).is_err() {
+        panic!("failed to set range");
+    }
+    if !bitmap.is_full() || bitmap.is_empty() {
+        panic!("unexpected usage of a full bitmap");
+    }
+
+    // Empty the bitmap.
+    if bitmap.clear_range(0, LENGTH).is_err() {
+        panic!("failed to clear range");
+    }
+    if !bitmap.is_empty() {
+        panic!("unexpected usage of an empty bitmap");
+    }
+}
+
+/// Attempts to find and iterate over bits in a [`Bitmap`].
+#[test]
+fn test_find_and_iterate() {
+    const WORD_BITS: usize = usize::BITS as usize;
+    const LENGTH: usize = 3 * WORD_BITS;
+    let indexes: [usize; 4] = [0, WORD_BITS - 1, WORD_BITS, 2 * WORD_BITS + 22];
+
+    // Create bitmap.
+    let mut bitmap: Bitmap = match Bitmap::new(LENGTH) {
+        Ok(bitmap) => bitmap,
+        Err(_) => panic!("failed to create bitmap"),
+    };
+
+    // Set some bits.
+    for index in indexes {
+        if bitmap.set(index).is_err() {
+            panic!("failed to set bit at index {}", index);
+        }
+    }
+
+    // Find bits.
+    if bitmap.find_next_set(1) != Some(WORD_BITS - 1)
+        || bitmap.find_next_set(2 * WORD_BITS + 23).is_some()
+    {
+        panic!("unexpected result when finding set bits");
+    }
+    if bitmap.find_next_clear(WORD_BITS - 1) != Some(WORD_BITS + 1)
+        || bitmap.find_next_clear(LENGTH).is_some()
+    {
+        panic!("unexpected result when finding cleared bits");
+    }
+
+    // Iterate over set bits.
+    if !bitmap.iter_set().eq(indexes.iter().copied()) {
+        panic!("unexpected set bits");
+    }
+
+    // Iterate over cleared bits.
+    let expected = (0..LENGTH).filter(|i| !indexes.contains(i));
+    if !bitmap.iter_clear().eq(expected) {
+        panic!("unexpected cleared bits");
+    }
+}
+
+/// Helper test function that creates a managed [`Bitmap`] with some bits set.
+fn test_helper_create_bitmap_with_bits(len: usize, indexes: &[usize]) -> Bitmap {
+    let mut bitmap: Bitmap = match Bitmap::new(len) {
+        Ok(bitmap) => bitmap,
+        Err(_) => panic!("failed to create bitmap"),
+    };
+
+    for index in indexes {
+        if bitmap.set(*index).is_err() {
+            panic!("failed to set bit at index {}", index);
+        }
+    }
+
+    bitmap
+}
+
+/// Attempts to combine two [`Bitmap`]s.
+#[test]
+fn test_set_algebra() {
+    const LENGTH: usize = usize::BITS as usize + 7;
+    let a: Bitmap = test_helper_create_bitmap_with_bits(LENGTH, &[0, 1, 2, LENGTH - 1]);
+    let b: Bitmap = test_helper_create_bitmap_with_bits(LENGTH, &[2, 3, LENGTH - 1]);
+
+    // Check union.
+    match a.union(&b) {
+        Ok(c) if c.iter_set().eq([0, 1, 2, 3, LENGTH - 1]) && c.count_ones() == 5 => {},
+        _ => panic!("unexpected union"),
+    }
+
+    // Check intersection.
+    match a.intersect(&b) {
+        Ok(c) if c.iter_set().eq([2, LENGTH - 1]) && c.count_ones() == 2 => {},
+        _ => panic!("unexpected intersection"),
+    }
+
+    // Check difference.
+    match a.difference(&b) {
+        Ok(c) if c.iter_set().eq([0, 1]) && c.count_ones() == 2 => {},
+        _ => panic!("unexpected difference"),
+    }
+
+    // Check symmetric difference.
+    match a.xor(&b) {
+        Ok(c) if c.iter_set().eq([0, 1, 3]) && c.count_ones() == 3 => {},
+        _ => panic!("unexpected symmetric difference"),
+    }
+
+    // Check complement.
+    match a.complement() {
+        Ok(c) if c.iter_set().eq(3..(LENGTH - 1)) && c.count_ones() == LENGTH - 4 => {},
+        _ => panic!("unexpected complement"),
+    }
+
+    // Check subset and equality.
+    match (a.intersect(&b), b.is_subset_of(&a)) {
+        (Ok(c), Ok(false)) if c.is_subset_of(&a).is_ok_and(|r| r) && c != a => {},
+        _ => panic!("unexpected subset relation"),
+    }
+    match a.try_clone() {
+        Ok(c) if c == a => {},
+        _ => panic!("unexpected clone"),
+    }
+}
+
+/// Attempts to combine two [`Bitmap`]s of different lengths.
+#[test]
+fn test_set_algebra_length_mismatch() {
+    let mut a: Bitmap = test_helper_create_bitmap_with_bits(8, &[0]);
+    let b: Bitmap = test_helper_create_bitmap_with_bits(16, &[1]);
+
+    match a.union_with(&b) {
+        Ok(_) => panic!("combined bitmaps of different lengths"),
+        Err(e) if e.code == ErrorCode::InvalidArgument => {},
+        Err(e) => panic!("unexpected error code (error={:?})", e),
+    }
+
+    // Check if the bitmap was left unmodified.
+    if !a.iter_set().eq([0]) || a.count_ones() != 1 {
+        panic!("failed operation modified the bitmap");
+    }
+
+    if a.is_subset_of(&b).is_ok() || a == b {
+        panic!("compared bitmaps of different lengths");
+    }
+}
+
+/// Attempts to allocate bits after inverting a [`Bitmap`].
+#[test]
+fn test_alloc_after_invert() {
+    const LENGTH: usize = usize::BITS as usize + 3;
+    let mut bitmap: Bitmap = test_helper_create_bitmap_with_bits(LENGTH, &[LENGTH - 2]);
+
+    // Invert the bitmap, so that only a single bit is left free.
+    bitmap.invert();
+    if bitmap.count_zeros() != 1 {
+        panic!("unexpected usage (expected={}, got={})", LENGTH - 1, bitmap.count_ones());
+    }
+
+    // Check if the only free bit is allocated.
+    match bitmap.alloc() {
+        Ok(index) if index == LENGTH - 2 => {},
+        Ok(index) => panic!("unexpected index (expected={}, got={})", LENGTH - 2, index),
+        Err(_) => panic!("failed to allocate bit"),
+    }
+    if bitmap.alloc().is_ok() {
+        panic!("allocated bit in a full bitmap");
+    }
 }
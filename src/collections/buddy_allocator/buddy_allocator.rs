@@ -0,0 +1,531 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::{
+    collections::{
+        bitmap::Bitmap,
+        intrusive_list::{
+            IntrusiveList,
+            ListAdapter,
+            ListLink,
+        },
+        raw_array::RawArray,
+    },
+    error::{
+        Error,
+        ErrorCode,
+    },
+    mm::{
+        self,
+        Alignment,
+    },
+};
+use ::core::{
+    mem,
+    ptr::{
+        self,
+        NonNull,
+    },
+};
+
+//==================================================================================================
+// Structures
+//==================================================================================================
+
+///
+/// # Description
+///
+/// A buddy allocator.
+///
+/// It hands out blocks whose size is a power-of-two multiple of a minimum block size. The exponent
+/// is the order of a block, and blocks of order `k` are aligned to their size. When a block is
+/// freed, it is coalesced with its buddy whenever the buddy is free as well.
+///
+/// It has the following layout in memory:
+///
+/// ```text
+/// +---------+-------+---------+--------------------------------------+------+
+/// | Padding | Index | Padding | Data Blocks                          | Tail |
+/// +---------+-------+---------+--------------------------------------+------+
+/// ```
+///
+/// The index holds one bit per block of each order, which is set if that block is free. Free
+/// blocks are additionally kept in one list per order, whose links are stored in the free blocks
+/// themselves.
+///
+#[derive(Debug)]
+pub struct BuddyAllocator {
+    /// An index that keeps track of free blocks of all orders.
+    index: Bitmap,
+    /// Free blocks, indexed by order.
+    free_lists: [IntrusiveList<FreeBlockAdapter>; BuddyAllocator::MAX_NUM_ORDERS],
+    /// Base address that block offsets are relative to. It is aligned to the largest block size.
+    base_addr: usize,
+    /// Start address of data blocks.
+    start_addr: usize,
+    /// End address of data blocks.
+    end_addr: usize,
+    /// Size of blocks of order zero (in bytes).
+    min_block_size: usize,
+    /// Largest order of blocks.
+    max_order: usize,
+    /// Number of bytes that are free.
+    free_bytes: usize,
+}
+
+///
+/// # Description
+///
+/// An adapter that links free blocks of a [`BuddyAllocator`].
+///
+#[derive(Debug)]
+struct FreeBlockAdapter;
+
+//==================================================================================================
+// Implementations
+//==================================================================================================
+
+impl BuddyAllocator {
+    /// Maximum number of orders.
+    pub const MAX_NUM_ORDERS: usize =
+        (Alignment::Align4194304 as usize).trailing_zeros() as usize + 1;
+
+    ///
+    /// # Description
+    ///
+    /// Creates a new buddy allocator on the memory region starting at `addr` with `len` bytes. The
+    /// buddy allocator is initialized with all blocks free.
+    ///
+    /// # Parameters
+    ///
+    /// - `addr`: Start address of the memory region.
+    /// - `len`: Length of the memory region in bytes.
+    /// - `min_block_size`: Size of blocks of order zero.
+    /// - `max_block_size`: Size of blocks of the largest order.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, a new buddy allocator is returned. Upon failure, an error is returned instead
+    /// and the memory may be left in an modified state.
+    ///
+    /// # Safety
+    ///
+    /// This function is unsafe for the following reasons:
+    /// - It assumes that the memory region starting at `addr` with `len` bytes is valid.
+    ///
+    pub unsafe fn from_raw_parts(
+        addr: *mut u8,
+        len: usize,
+        min_block_size: Alignment,
+        max_block_size: Alignment,
+    ) -> Result<Self, Error> {
+        // Check if length is invalid.
+        if len == 0 || len >= i32::MAX as usize {
+            return Err(Error::new(ErrorCode::InvalidArgument, "invalid length"));
+        }
+
+        // Check if the memory region wraps around.
+        if addr.wrapping_add(len) < addr {
+            return Err(Error::new(ErrorCode::InvalidArgument, "wrapping memory region"));
+        }
+
+        // Check if block sizes are valid. Free blocks must be able to hold their links.
+        if (min_block_size as usize) < mem::size_of::<ListLink>()
+            || (min_block_size as usize) < mem::align_of::<ListLink>()
+            || max_block_size < min_block_size
+        {
+            return Err(Error::new(ErrorCode::InvalidArgument, "invalid block size"));
+        }
+
+        let min_size: usize = min_block_size as usize;
+        let max_order: usize = (max_block_size as usize).trailing_zeros() as usize
+            - min_size.trailing_zeros() as usize;
+
+        // Compute layout of the buddy allocator. The index covers all blocks from the base address
+        // up to the end of the memory region.
+        let end_addr: usize = addr as usize + len;
+        let base_addr: usize = mm::align_down(addr as usize, max_block_size);
+        let num_bits: usize = (0..=max_order)
+            .map(|order| (end_addr - base_addr).div_ceil(min_size << order))
+            .sum();
+        let index_addr: usize = (addr as usize).next_multiple_of(mem::align_of::<usize>());
+        let index_len: usize = Bitmap::words_for(num_bits) * mem::size_of::<usize>();
+        let start_addr: usize = mm::align_up(index_addr + index_len, min_block_size);
+        if start_addr + min_size > end_addr {
+            return Err(Error::new(ErrorCode::InvalidArgument, "memory region is too small"));
+        }
+
+        // Instantiate index.
+        let storage: RawArray<usize> =
+            RawArray::from_raw_parts(index_addr as *mut usize, Bitmap::words_for(num_bits))?;
+        let index: Bitmap = Bitmap::from_raw_array(storage, num_bits)?;
+
+        let mut buddy: BuddyAllocator = BuddyAllocator {
+            index,
+            free_lists: [const { IntrusiveList::new() }; BuddyAllocator::MAX_NUM_ORDERS],
+            base_addr,
+            start_addr,
+            end_addr,
+            min_block_size: min_size,
+            max_order,
+            free_bytes: 0,
+        };
+
+        // Carve data blocks into the largest blocks that are aligned and fit in the memory region.
+        let mut block: usize = start_addr;
+        loop {
+            let order: Option<usize> = (0..=max_order).rev().find(|order| {
+                let size: usize = buddy.block_size(*order);
+                block % size == 0 && block + size <= end_addr
+            });
+            match order {
+                Some(order) => {
+                    buddy.insert_free(block, order)?;
+                    block += buddy.block_size(order);
+                },
+                None => break,
+            }
+        }
+
+        Ok(buddy)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the size of blocks of a given order.
+    ///
+    /// # Parameters
+    ///
+    /// - `order`: Order of blocks.
+    ///
+    /// # Returns
+    ///
+    /// The size of blocks of the given order (in bytes).
+    ///
+    pub fn block_size(&self, order: usize) -> usize {
+        self.min_block_size << order
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the largest order of blocks.
+    ///
+    /// # Returns
+    ///
+    /// The largest order of blocks.
+    ///
+    pub fn max_order(&self) -> usize {
+        self.max_order
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the number of bytes in data blocks that are managed by the buddy allocator.
+    ///
+    /// # Returns
+    ///
+    /// The number of bytes in data blocks that are managed by the buddy allocator.
+    ///
+    pub fn total_bytes(&self) -> usize {
+        self.end_addr - self.start_addr
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the number of bytes that are free in the buddy allocator.
+    ///
+    /// # Returns
+    ///
+    /// The number of bytes that are free in the buddy allocator.
+    ///
+    pub fn free_bytes(&self) -> usize {
+        self.free_bytes
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the number of free blocks of a given order.
+    ///
+    /// # Parameters
+    ///
+    /// - `order`: Order of blocks.
+    ///
+    /// # Returns
+    ///
+    /// The number of free blocks of the given order.
+    ///
+    pub fn free_blocks(&self, order: usize) -> usize {
+        match self.free_lists.get(order) {
+            Some(list) if order <= self.max_order => list.len(),
+            _ => 0,
+        }
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the largest order of blocks that are free.
+    ///
+    /// # Returns
+    ///
+    /// The largest order of blocks that are free, if any.
+    ///
+    pub fn largest_free_order(&self) -> Option<usize> {
+        (0..=self.max_order)
+            .rev()
+            .find(|order| !self.free_lists[*order].is_empty())
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Computes the external fragmentation of free memory, that is, the share of free bytes that
+    /// do not lie in the largest free block.
+    ///
+    /// # Returns
+    ///
+    /// The external fragmentation of free memory, as a percentage. If no memory is free, zero is
+    /// returned.
+    ///
+    pub fn fragmentation(&self) -> usize {
+        match self.largest_free_order() {
+            Some(order) => 100 - (self.block_size(order) * 100) / self.free_bytes,
+            None => 0,
+        }
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Allocates a block of a given order from the buddy allocator. Larger blocks are split as
+    /// needed.
+    ///
+    /// # Parameters
+    ///
+    /// - `order`: Order of the block.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, a pointer to the allocated block is returned. Upon failure, an error is
+    /// returned instead.
+    ///
+    pub fn allocate(&mut self, order: usize) -> Result<*mut u8, Error> {
+        // Check if order is invalid.
+        if order > self.max_order {
+            return Err(Error::new(ErrorCode::InvalidArgument, "invalid order"));
+        }
+
+        // Find the smallest free block that is large enough.
+        let mut current: usize =
+            match (order..=self.max_order).find(|order| !self.free_lists[*order].is_empty()) {
+                Some(current) => current,
+                None => return Err(Error::new(ErrorCode::OutOfMemory, "out of memory")),
+            };
+        let block: usize = match self.free_lists[current].front() {
+            Some(link) => link.as_ptr() as usize,
+            None => return Err(Error::new(ErrorCode::OutOfMemory, "out of memory")),
+        };
+        self.remove_free(block, current)?;
+
+        // Split the block, releasing upper halves.
+        while current > order {
+            current -= 1;
+            self.insert_free(block + self.block_size(current), current)?;
+        }
+
+        Ok(block as *mut u8)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Frees a block of a given order from the buddy allocator. The block is coalesced with its
+    /// buddies as long as they are free.
+    ///
+    /// # Parameters
+    ///
+    /// - `ptr`: Pointer to the block to free.
+    /// - `order`: Order of the block, as passed to [`BuddyAllocator::allocate()`].
+    ///
+    /// # Returns
+    ///
+    /// Upon success, `Ok(())` is returned. Upon failure, an error is returned instead.
+    ///
+    pub fn deallocate(&mut self, ptr: *const u8, order: usize) -> Result<(), Error> {
+        // Check if order is invalid.
+        if order > self.max_order {
+            return Err(Error::new(ErrorCode::InvalidArgument, "invalid order"));
+        }
+
+        // Check if the block lies in a memory region that is not managed by this allocator.
+        let mut block: usize = ptr as usize;
+        if block < self.start_addr || block + self.block_size(order) > self.end_addr {
+            return Err(Error::new(ErrorCode::BadAddress, "pointer out of bounds"));
+        }
+        if block % self.block_size(order) != 0 {
+            return Err(Error::new(ErrorCode::BadAddress, "unaligned pointer"));
+        }
+
+        // Check if the block, or any block that contains it, is already free.
+        for current in order..=self.max_order {
+            let container: usize = block - block % self.block_size(current);
+            if container >= self.start_addr && self.is_free(container, current)? {
+                return Err(Error::new(ErrorCode::BadAddress, "block is already free"));
+            }
+        }
+
+        // Check if any smaller block that lies in the block is free, which happens if the order
+        // does not match the one that the block was allocated with.
+        for current in 0..order {
+            let first: usize = self.bit(block, current);
+            let count: usize = 1 << (order - current);
+            for bit in first..first + count {
+                if self.index.test(bit)? {
+                    return Err(Error::new(ErrorCode::BadAddress, "block overlaps a free block"));
+                }
+            }
+        }
+
+        // Coalesce the block with its buddies.
+        let mut current: usize = order;
+        while current < self.max_order {
+            let buddy: usize =
+                self.base_addr + ((block - self.base_addr) ^ self.block_size(current));
+            if buddy < self.start_addr
+                || buddy + self.block_size(current) > self.end_addr
+                || !self.is_free(buddy, current)?
+            {
+                break;
+            }
+            self.remove_free(buddy, current)?;
+            block = block.min(buddy);
+            current += 1;
+        }
+
+        self.insert_free(block, current)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Checks if a block is free.
+    ///
+    /// # Parameters
+    ///
+    /// - `block`: Address of the block.
+    /// - `order`: Order of the block.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, `Ok(true)` is returned if the block is free, `Ok(false)` is returned
+    /// otherwise. Upon failure, an error is returned instead.
+    ///
+    fn is_free(&self, block: usize, order: usize) -> Result<bool, Error> {
+        self.index.test(self.bit(block, order))
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Inserts a block in the free list of its order.
+    ///
+    /// # Parameters
+    ///
+    /// - `block`: Address of the block.
+    /// - `order`: Order of the block.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, `Ok(())` is returned. Upon failure, an error is returned instead.
+    ///
+    fn insert_free(&mut self, block: usize, order: usize) -> Result<(), Error> {
+        self.index.set(self.bit(block, order))?;
+
+        // Safety: the block is free, and it is large enough and suitably aligned to hold a link.
+        let link: *mut ListLink = block as *mut ListLink;
+        unsafe {
+            ptr::write(link, ListLink::new());
+            self.free_lists[order].push_back(NonNull::new_unchecked(link))?;
+        }
+        self.free_bytes += self.block_size(order);
+
+        Ok(())
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Removes a block from the free list of its order.
+    ///
+    /// # Parameters
+    ///
+    /// - `block`: Address of the block.
+    /// - `order`: Order of the block.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, `Ok(())` is returned. Upon failure, an error is returned instead.
+    ///
+    fn remove_free(&mut self, block: usize, order: usize) -> Result<(), Error> {
+        self.index.clear(self.bit(block, order))?;
+
+        // Safety: the block is in the free list of its order.
+        unsafe { self.free_lists[order].remove(NonNull::new_unchecked(block as *mut ListLink))? };
+        self.free_bytes -= self.block_size(order);
+
+        Ok(())
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Computes the bit of the index that refers to a block.
+    ///
+    /// # Parameters
+    ///
+    /// - `block`: Address of the block.
+    /// - `order`: Order of the block.
+    ///
+    /// # Returns
+    ///
+    /// The bit of the index that refers to the block.
+    ///
+    fn bit(&self, block: usize, order: usize) -> usize {
+        let span: usize = self.end_addr - self.base_addr;
+        let offset: usize = (0..order)
+            .map(|order| span.div_ceil(self.block_size(order)))
+            .sum();
+        offset + (block - self.base_addr) / self.block_size(order)
+    }
+}
+
+impl Drop for BuddyAllocator {
+    fn drop(&mut self) {
+        // Free blocks need not be unlinked, as their memory is no longer managed.
+        for list in self.free_lists.iter_mut() {
+            mem::forget(mem::take(list));
+        }
+    }
+}
+
+// Safety: a free block is its own link, which is stored at the start of the block.
+unsafe impl ListAdapter for FreeBlockAdapter {
+    type Value = ListLink;
+
+    fn link(value: &ListLink) -> &ListLink {
+        value
+    }
+
+    unsafe fn from_link(link: NonNull<ListLink>) -> NonNull<ListLink> {
+        link
+    }
+}
@@ -0,0 +1,17 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Modules
+//==================================================================================================
+
+mod buddy_allocator;
+
+#[cfg(test)]
+mod test;
+
+//==================================================================================================
+// Exports
+//==================================================================================================
+
+pub use buddy_allocator::*;
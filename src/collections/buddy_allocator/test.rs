@@ -0,0 +1,221 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::{
+    collections::BuddyAllocator,
+    error::ErrorCode,
+    mm::Alignment,
+};
+use ::alloc::vec::Vec;
+
+//==================================================================================================
+// Unit Tests
+//==================================================================================================
+
+/// Size of memory regions used in tests.
+const REGION_SIZE: usize = 16384;
+
+/// Size of blocks of order zero used in tests.
+const MIN_BLOCK_SIZE: Alignment = Alignment::Align64;
+
+/// Size of blocks of the largest order used in tests.
+const MAX_BLOCK_SIZE: Alignment = Alignment::Align4096;
+
+/// A memory region that is suitably aligned for buddy allocators used in tests.
+#[repr(C, align(4096))]
+struct Region([u8; REGION_SIZE]);
+
+/// Helper test function that creates a [`BuddyAllocator`] on a memory region.
+fn test_helper_create_buddy(region: &mut Region) -> BuddyAllocator {
+    match unsafe {
+        BuddyAllocator::from_raw_parts(
+            region.0.as_mut_ptr(),
+            REGION_SIZE,
+            MIN_BLOCK_SIZE,
+            MAX_BLOCK_SIZE,
+        )
+    } {
+        Ok(buddy) => buddy,
+        Err(e) => panic!("failed to create buddy allocator (error={:?})", e),
+    }
+}
+
+/// Attempts to allocate all blocks of order zero and free them, so that they are coalesced.
+#[test]
+fn test_allocate_all_and_coalesce() {
+    let mut region: Region = Region([0; REGION_SIZE]);
+    let start: usize = region.0.as_ptr() as usize;
+    let mut buddy: BuddyAllocator = test_helper_create_buddy(&mut region);
+    let initial: Vec<usize> = (0..=buddy.max_order())
+        .map(|o| buddy.free_blocks(o))
+        .collect();
+
+    // Check if the index took some memory, but not whole blocks of the largest order.
+    if buddy.free_bytes() != buddy.total_bytes() || buddy.total_bytes() >= REGION_SIZE {
+        panic!("unexpected statistics (total={})", buddy.total_bytes());
+    }
+    if buddy.free_blocks(buddy.max_order()) != REGION_SIZE / MAX_BLOCK_SIZE as usize - 1 {
+        panic!("unexpected number of blocks (got={})", buddy.free_blocks(buddy.max_order()));
+    }
+
+    // Allocate all blocks of order zero.
+    let mut blocks: Vec<*mut u8> = Vec::new();
+    while let Ok(ptr) = buddy.allocate(0) {
+        let addr: usize = ptr as usize;
+        if addr % MIN_BLOCK_SIZE as usize != 0
+            || addr < start
+            || addr + MIN_BLOCK_SIZE as usize > start + REGION_SIZE
+        {
+            panic!("misplaced block (addr={:#x})", addr);
+        }
+        blocks.push(ptr);
+    }
+    if blocks.len() * MIN_BLOCK_SIZE as usize != buddy.total_bytes() || buddy.free_bytes() != 0 {
+        panic!("unexpected number of blocks (got={})", blocks.len());
+    }
+
+    // Free all blocks, in reverse order, and check that they are coalesced back.
+    for ptr in blocks.iter().rev() {
+        if let Err(e) = buddy.deallocate(*ptr, 0) {
+            panic!("failed to deallocate block (error={:?})", e);
+        }
+    }
+    let coalesced: Vec<usize> = (0..=buddy.max_order())
+        .map(|o| buddy.free_blocks(o))
+        .collect();
+    if coalesced != initial {
+        panic!("blocks were not coalesced (expected={:?}, got={:?})", initial, coalesced);
+    }
+}
+
+/// Attempts to split a block and measure fragmentation.
+#[test]
+fn test_split_and_fragmentation() {
+    let mut region: Region = Region([0; REGION_SIZE]);
+    let mut buddy: BuddyAllocator = test_helper_create_buddy(&mut region);
+    let max_order: usize = buddy.max_order();
+
+    // Allocate all free blocks, except for one block of the largest order.
+    let mut blocks: Vec<*mut u8> = Vec::new();
+    for order in 0..=max_order {
+        let keep: usize = if order == max_order { 1 } else { 0 };
+        while buddy.free_blocks(order) > keep {
+            match buddy.allocate(order) {
+                Ok(ptr) => blocks.push(ptr),
+                Err(e) => panic!("failed to allocate block (error={:?})", e),
+            }
+        }
+    }
+    let before: usize = buddy.fragmentation();
+    if before != 0 {
+        panic!("unexpected fragmentation (got={})", before);
+    }
+
+    // Split the last block of the largest order.
+    let small: *mut u8 = match buddy.allocate(1) {
+        Ok(ptr) => ptr,
+        Err(e) => panic!("failed to allocate block (error={:?})", e),
+    };
+    if (small as usize) % buddy.block_size(max_order) != 0 {
+        panic!("split block does not start at its parent (addr={:?})", small);
+    }
+    for order in 1..max_order {
+        if buddy.free_blocks(order) != 1 {
+            panic!("split did not release a block of order {}", order);
+        }
+    }
+    if buddy.largest_free_order() != Some(max_order - 1) || buddy.fragmentation() <= before {
+        panic!("unexpected fragmentation (before={}, after={})", before, buddy.fragmentation());
+    }
+
+    // Check if freeing the small block restores the large one.
+    if let Err(e) = buddy.deallocate(small, 1) {
+        panic!("failed to deallocate block (error={:?})", e);
+    }
+    if buddy.largest_free_order() != Some(max_order) || buddy.fragmentation() != before {
+        panic!("block was not coalesced (fragmentation={})", buddy.fragmentation());
+    }
+}
+
+/// Attempts to free blocks that are invalid.
+#[test]
+fn test_deallocate_invalid() {
+    let mut region: Region = Region([0; REGION_SIZE]);
+    let mut buddy: BuddyAllocator = test_helper_create_buddy(&mut region);
+
+    let ptr: *mut u8 = match buddy.allocate(2) {
+        Ok(ptr) => ptr,
+        Err(e) => panic!("failed to allocate block (error={:?})", e),
+    };
+
+    // Free a block that is misaligned for its order.
+    match buddy.deallocate(ptr.wrapping_add(MIN_BLOCK_SIZE as usize), 1) {
+        Ok(_) => panic!("deallocated misaligned block"),
+        Err(e) if e.code == ErrorCode::BadAddress => {},
+        Err(e) => panic!("unexpected error code (error={:?})", e),
+    }
+
+    // Free a block twice.
+    if let Err(e) = buddy.deallocate(ptr, 2) {
+        panic!("failed to deallocate block (error={:?})", e);
+    }
+    match buddy.deallocate(ptr, 2) {
+        Ok(_) => panic!("deallocated block twice"),
+        Err(e) if e.code == ErrorCode::BadAddress => {},
+        Err(e) => panic!("unexpected error code (error={:?})", e),
+    }
+
+    // Free a block with an invalid order.
+    match buddy.deallocate(ptr, buddy.max_order() + 1) {
+        Ok(_) => panic!("deallocated block with invalid order"),
+        Err(e) if e.code == ErrorCode::InvalidArgument => {},
+        Err(e) => panic!("unexpected error code (error={:?})", e),
+    }
+}
+
+/// Attempts to free a block with a larger order than the one it was allocated with.
+#[test]
+fn test_deallocate_wrong_order() {
+    let mut region: Region = Region([0; REGION_SIZE]);
+    let mut buddy: BuddyAllocator = test_helper_create_buddy(&mut region);
+
+    // Drain blocks of order zero, so that the next allocation splits a larger block.
+    let mut drained: Vec<*mut u8> = Vec::new();
+    while buddy.free_blocks(0) > 0 {
+        match buddy.allocate(0) {
+            Ok(ptr) => drained.push(ptr),
+            Err(e) => panic!("failed to allocate block (error={:?})", e),
+        }
+    }
+
+    // Allocate a block of order zero, whose buddy is left free by the split.
+    let ptr: *mut u8 = match buddy.allocate(0) {
+        Ok(ptr) => ptr,
+        Err(e) => panic!("failed to allocate block (error={:?})", e),
+    };
+    let free_bytes: usize = buddy.free_bytes();
+
+    // Free the block as if it had order one, which would overlap its free buddy.
+    match buddy.deallocate(ptr, 1) {
+        Ok(_) => panic!("deallocated block that overlaps a free block"),
+        Err(e) if e.code == ErrorCode::BadAddress => {},
+        Err(e) => panic!("unexpected error code (error={:?})", e),
+    }
+    if buddy.free_bytes() != free_bytes {
+        panic!("allocator was modified (free={})", buddy.free_bytes());
+    }
+
+    // Free all blocks with their actual order.
+    for ptr in drained.into_iter().chain([ptr]) {
+        if let Err(e) = buddy.deallocate(ptr, 0) {
+            panic!("failed to deallocate block (error={:?})", e);
+        }
+    }
+    if buddy.free_bytes() != buddy.total_bytes() {
+        panic!("blocks were not released (free={})", buddy.free_bytes());
+    }
+}
@@ -0,0 +1,473 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::{
+    collections::{
+        fixed_hash_map::FnvBuildHasher,
+        raw_array::RawArray,
+    },
+    error::{
+        Error,
+        ErrorCode,
+    },
+};
+use ::core::{
+    borrow::Borrow,
+    hash::{
+        BuildHasher,
+        Hash,
+    },
+    mem::{
+        self,
+        MaybeUninit,
+    },
+};
+
+//==================================================================================================
+// Structures
+//==================================================================================================
+
+///
+/// # Description
+///
+/// A hash map with fixed capacity that never allocates after it is created.
+///
+/// Entries live in a [`RawArray`] of buckets, and collisions are resolved with open addressing and
+/// linear probing. Removed entries leave tombstones behind, so that probe sequences of other
+/// entries are not broken. Keys are hashed with a pluggable [`BuildHasher`], which defaults to
+/// [`FnvBuildHasher`].
+///
+#[derive(Debug)]
+pub struct FixedHashMap<K, V, S = FnvBuildHasher> {
+    /// Buckets that hold entries. All buckets are initialized.
+    buckets: RawArray<MaybeUninit<Bucket<K, V>>>,
+    /// Number of entries in the hash map.
+    len: usize,
+    /// Builder of hashers for keys.
+    hash_builder: S,
+}
+
+///
+/// # Description
+///
+/// A bucket of a [`FixedHashMap`].
+///
+#[derive(Debug)]
+pub struct Bucket<K, V> {
+    /// State of the bucket.
+    state: BucketState<K, V>,
+}
+
+///
+/// # Description
+///
+/// State of a [`Bucket`].
+///
+#[derive(Debug)]
+enum BucketState<K, V> {
+    /// The bucket has never held an entry since the last time the probe sequence was cut.
+    Empty,
+    /// The bucket held an entry that was removed.
+    Tombstone,
+    /// The bucket holds an entry.
+    Occupied(K, V),
+}
+
+///
+/// # Description
+///
+/// An iterator over the entries of a [`FixedHashMap`].
+///
+#[derive(Debug)]
+pub struct FixedHashMapIter<'a, K, V> {
+    /// Buckets that are iterated over.
+    buckets: &'a [MaybeUninit<Bucket<K, V>>],
+    /// Index of the next bucket to visit.
+    index: usize,
+}
+
+//==================================================================================================
+// Implementations
+//==================================================================================================
+
+impl<K: Hash + Eq, V> FixedHashMap<K, V, FnvBuildHasher> {
+    ///
+    /// # Description
+    ///
+    /// Creates a new empty hash map that uses the default hasher.
+    ///
+    /// # Parameters
+    ///
+    /// - `capacity`: Maximum number of entries in the hash map.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, a new empty hash map is returned. Upon failure, an error is returned instead.
+    ///
+    pub fn new(capacity: usize) -> Result<Self, Error> {
+        Self::with_hasher(capacity, FnvBuildHasher::default())
+    }
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher> FixedHashMap<K, V, S> {
+    ///
+    /// # Description
+    ///
+    /// Creates a new empty hash map that uses a given hasher.
+    ///
+    /// # Parameters
+    ///
+    /// - `capacity`: Maximum number of entries in the hash map.
+    /// - `hash_builder`: Builder of hashers for keys.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, a new empty hash map is returned. Upon failure, an error is returned instead.
+    ///
+    pub fn with_hasher(capacity: usize, hash_builder: S) -> Result<Self, Error> {
+        Ok(Self::from_raw_array(RawArray::new(capacity)?, hash_builder))
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Creates a new empty hash map on top of a raw array. Each element of the array holds one
+    /// bucket, and its contents are overwritten.
+    ///
+    /// # Parameters
+    ///
+    /// - `buckets`: Raw array that holds buckets.
+    /// - `hash_builder`: Builder of hashers for keys.
+    ///
+    /// # Returns
+    ///
+    /// A new empty hash map.
+    ///
+    pub fn from_raw_array(
+        mut buckets: RawArray<MaybeUninit<Bucket<K, V>>>,
+        hash_builder: S,
+    ) -> Self {
+        for bucket in buckets.iter_mut() {
+            bucket.write(Bucket {
+                state: BucketState::Empty,
+            });
+        }
+
+        Self {
+            buckets,
+            len: 0,
+            hash_builder,
+        }
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the maximum number of entries in the hash map.
+    ///
+    /// # Returns
+    ///
+    /// The maximum number of entries in the hash map.
+    ///
+    pub fn capacity(&self) -> usize {
+        self.buckets.len()
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the number of entries in the hash map.
+    ///
+    /// # Returns
+    ///
+    /// The number of entries in the hash map.
+    ///
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Checks if the hash map is empty.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the hash map has no entries, `false` otherwise.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Inserts an entry into the hash map. If the key is already present, its value is replaced.
+    ///
+    /// # Parameters
+    ///
+    /// - `key`: Key of the entry.
+    /// - `value`: Value of the entry.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, the value that was previously associated with the key, if any, is returned.
+    /// Upon failure, `key` and `value` are returned back along with an error, so that the caller
+    /// may retry.
+    ///
+    pub fn insert(&mut self, key: K, value: V) -> Result<Option<V>, (K, V, Error)> {
+        // Check if the key is already present.
+        if let Some(index) = self.find(&key) {
+            if let BucketState::Occupied(_, old) = &mut self.bucket_mut(index).state {
+                return Ok(Some(mem::replace(old, value)));
+            }
+        }
+
+        // Find a free bucket along the probe sequence of the key.
+        let start: usize = self.home(&key);
+        for i in 0..self.capacity() {
+            let index: usize = (start + i) % self.capacity();
+            let bucket: &mut Bucket<K, V> = self.bucket_mut(index);
+            if !matches!(bucket.state, BucketState::Occupied(..)) {
+                bucket.state = BucketState::Occupied(key, value);
+                self.len += 1;
+                return Ok(None);
+            }
+        }
+
+        Err((key, value, Error::new(ErrorCode::NoBufferSpace, "hash map is full")))
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns a reference to the value that is associated with a key.
+    ///
+    /// # Parameters
+    ///
+    /// - `key`: Key to look up.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the value that is associated with the key, if any.
+    ///
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index: usize = self.find(key)?;
+        match &self.bucket(index).state {
+            BucketState::Occupied(_, value) => Some(value),
+            _ => None,
+        }
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns a mutable reference to the value that is associated with a key.
+    ///
+    /// # Parameters
+    ///
+    /// - `key`: Key to look up.
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to the value that is associated with the key, if any.
+    ///
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index: usize = self.find(key)?;
+        match &mut self.bucket_mut(index).state {
+            BucketState::Occupied(_, value) => Some(value),
+            _ => None,
+        }
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Checks if a key is present in the hash map.
+    ///
+    /// # Parameters
+    ///
+    /// - `key`: Key to look up.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the key is present in the hash map, `false` otherwise.
+    ///
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.find(key).is_some()
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Removes an entry from the hash map.
+    ///
+    /// # Parameters
+    ///
+    /// - `key`: Key of the entry to remove.
+    ///
+    /// # Returns
+    ///
+    /// The value that was associated with the key, if any.
+    ///
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index: usize = self.find(key)?;
+
+        // Leave a tombstone behind, unless no probe sequence continues past this bucket.
+        let next: usize = (index + 1) % self.capacity();
+        let state: BucketState<K, V> = match self.bucket(next).state {
+            BucketState::Empty => BucketState::Empty,
+            _ => BucketState::Tombstone,
+        };
+
+        self.len -= 1;
+        match mem::replace(&mut self.bucket_mut(index).state, state) {
+            BucketState::Occupied(_, value) => Some(value),
+            _ => None,
+        }
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns an iterator over the entries of the hash map, in arbitrary order.
+    ///
+    /// # Returns
+    ///
+    /// An iterator over the entries of the hash map.
+    ///
+    pub fn iter(&self) -> FixedHashMapIter<'_, K, V> {
+        FixedHashMapIter {
+            buckets: &self.buckets,
+            index: 0,
+        }
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Finds the bucket that holds a key.
+    ///
+    /// # Parameters
+    ///
+    /// - `key`: Key to look up.
+    ///
+    /// # Returns
+    ///
+    /// The index of the bucket that holds the key, if any.
+    ///
+    fn find<Q>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let start: usize = self.home(key);
+        for i in 0..self.capacity() {
+            let index: usize = (start + i) % self.capacity();
+            match &self.bucket(index).state {
+                BucketState::Empty => return None,
+                BucketState::Occupied(k, _) if k.borrow() == key => return Some(index),
+                _ => {},
+            }
+        }
+        None
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Computes the first bucket in the probe sequence of a key.
+    ///
+    /// # Parameters
+    ///
+    /// - `key`: Target key.
+    ///
+    /// # Returns
+    ///
+    /// The index of the first bucket in the probe sequence of the key.
+    ///
+    fn home<Q: Hash + ?Sized>(&self, key: &Q) -> usize {
+        (self.hash_builder.hash_one(key) % self.capacity() as u64) as usize
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns a reference to a bucket.
+    ///
+    /// # Parameters
+    ///
+    /// - `index`: Index of the target bucket.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the target bucket.
+    ///
+    fn bucket(&self, index: usize) -> &Bucket<K, V> {
+        // Safety: all buckets are initialized when the hash map is created.
+        unsafe { self.buckets[index].assume_init_ref() }
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns a mutable reference to a bucket.
+    ///
+    /// # Parameters
+    ///
+    /// - `index`: Index of the target bucket.
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to the target bucket.
+    ///
+    fn bucket_mut(&mut self, index: usize) -> &mut Bucket<K, V> {
+        // Safety: all buckets are initialized when the hash map is created.
+        unsafe { self.buckets[index].assume_init_mut() }
+    }
+}
+
+impl<K, V, S> Drop for FixedHashMap<K, V, S> {
+    fn drop(&mut self) {
+        for bucket in self.buckets.iter_mut() {
+            // Safety: all buckets are initialized when the hash map is created.
+            unsafe { bucket.assume_init_drop() };
+        }
+    }
+}
+
+impl<'a, K, V> Iterator for FixedHashMapIter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.index < self.buckets.len() {
+            // Safety: all buckets are initialized when the hash map is created.
+            let bucket: &'a Bucket<K, V> = unsafe { self.buckets[self.index].assume_init_ref() };
+            self.index += 1;
+            if let BucketState::Occupied(key, value) = &bucket.state {
+                return Some((key, value));
+            }
+        }
+        None
+    }
+}
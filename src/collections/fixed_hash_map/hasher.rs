@@ -0,0 +1,64 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use ::core::hash::{
+    BuildHasherDefault,
+    Hasher,
+};
+
+//==================================================================================================
+// Structures
+//==================================================================================================
+
+///
+/// # Description
+///
+/// A hasher that implements the 64-bit FNV-1a hash function.
+///
+/// It is fast for the small keys that are common in kernel tables, such as identifiers, but it
+/// offers no protection against keys that are crafted to collide.
+///
+#[derive(Debug, Clone, Copy)]
+pub struct FnvHasher(u64);
+
+///
+/// # Description
+///
+/// A builder of [`FnvHasher`]s.
+///
+pub type FnvBuildHasher = BuildHasherDefault<FnvHasher>;
+
+//==================================================================================================
+// Implementations
+//==================================================================================================
+
+impl FnvHasher {
+    /// Offset basis of the FNV-1a hash function.
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+
+    /// Prime of the FNV-1a hash function.
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+}
+
+impl Default for FnvHasher {
+    fn default() -> Self {
+        Self(Self::OFFSET_BASIS)
+    }
+}
+
+impl Hasher for FnvHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= *byte as u64;
+            self.0 = self.0.wrapping_mul(Self::PRIME);
+        }
+    }
+}
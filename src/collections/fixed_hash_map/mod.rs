@@ -0,0 +1,19 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Modules
+//==================================================================================================
+
+mod fixed_hash_map;
+mod hasher;
+
+#[cfg(test)]
+mod test;
+
+//==================================================================================================
+// Exports
+//==================================================================================================
+
+pub use fixed_hash_map::*;
+pub use hasher::*;
@@ -0,0 +1,174 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::{
+    collections::{
+        Bucket,
+        FixedHashMap,
+        FnvBuildHasher,
+        RawArray,
+    },
+    error::ErrorCode,
+    event::{
+        Event,
+        EventDescriptor,
+        InterruptEvent,
+    },
+    pm::ProcessIdentifier,
+};
+use ::alloc::vec::Vec;
+use ::core::{
+    hash::{
+        BuildHasher,
+        Hasher,
+    },
+    mem::MaybeUninit,
+};
+
+//==================================================================================================
+// Unit Tests
+//==================================================================================================
+
+/// Capacity of hash maps used in tests.
+const CAPACITY: usize = 8;
+
+/// A hasher that maps all keys to the same bucket, to force collisions.
+#[derive(Default)]
+struct CollidingHasher;
+
+impl Hasher for CollidingHasher {
+    fn finish(&self) -> u64 {
+        0
+    }
+
+    fn write(&mut self, _bytes: &[u8]) {}
+}
+
+impl BuildHasher for CollidingHasher {
+    type Hasher = CollidingHasher;
+
+    fn build_hasher(&self) -> CollidingHasher {
+        CollidingHasher
+    }
+}
+
+/// Attempts to insert, get and remove entries keyed by process identifiers.
+#[test]
+fn test_insert_get_remove() {
+    let mut map: FixedHashMap<ProcessIdentifier, usize> = match FixedHashMap::new(CAPACITY) {
+        Ok(map) => map,
+        Err(e) => panic!("failed to create hash map (error={:?})", e),
+    };
+
+    // Fill the hash map.
+    for raw in 0..CAPACITY as u32 {
+        match map.insert(ProcessIdentifier::from(raw), raw as usize) {
+            Ok(None) => {},
+            Ok(Some(old)) => panic!("unexpected previous value (got={})", old),
+            Err((_, _, e)) => panic!("failed to insert entry (error={:?})", e),
+        }
+    }
+    match map.insert(ProcessIdentifier::from(CAPACITY as u32), 42) {
+        Ok(_) => panic!("inserted entry into a full hash map"),
+        Err((key, value, e)) if e.code == ErrorCode::NoBufferSpace => {
+            if key != ProcessIdentifier::from(CAPACITY as u32) || value != 42 {
+                panic!("entry was not handed back (key={:?}, value={})", key, value);
+            }
+        },
+        Err((_, _, e)) => panic!("unexpected error code (error={:?})", e),
+    }
+
+    // Replace and look up values.
+    match map.insert(ProcessIdentifier::PROCD, 100) {
+        Ok(Some(1)) => {},
+        Ok(old) => panic!("unexpected previous value (got={:?})", old),
+        Err((_, _, e)) => panic!("failed to replace entry (error={:?})", e),
+    }
+    if let Some(value) = map.get_mut(&ProcessIdentifier::KERNEL) {
+        *value = 200;
+    }
+    if map.get(&ProcessIdentifier::KERNEL) != Some(&200)
+        || map.get(&ProcessIdentifier::PROCD) != Some(&100)
+    {
+        panic!("unexpected values after replacement");
+    }
+
+    // Remove an entry and reuse its bucket.
+    if map.remove(&ProcessIdentifier::KERNEL) != Some(200)
+        || map.remove(&ProcessIdentifier::KERNEL).is_some()
+    {
+        panic!("failed to remove entry");
+    }
+    if map.contains_key(&ProcessIdentifier::KERNEL) || map.len() != CAPACITY - 1 {
+        panic!("removed entry is still present");
+    }
+    if let Err((_, _, e)) = map.insert(ProcessIdentifier::from(CAPACITY as u32), 0) {
+        panic!("failed to insert entry (error={:?})", e);
+    }
+
+    // Check if iteration visits all entries.
+    let mut values: Vec<usize> = map.iter().map(|(_, value)| *value).collect();
+    values.sort();
+    if values != [0, 2, 3, 4, 5, 6, 7, 100] {
+        panic!("unexpected values (got={:?})", values);
+    }
+}
+
+/// Attempts to look up colliding keys in a hash map with tombstones.
+#[test]
+fn test_collisions() {
+    let mut map: FixedHashMap<usize, usize, CollidingHasher> =
+        match FixedHashMap::with_hasher(CAPACITY, CollidingHasher) {
+            Ok(map) => map,
+            Err(e) => panic!("failed to create hash map (error={:?})", e),
+        };
+    for key in 0..4 {
+        if let Err((_, _, e)) = map.insert(key, key) {
+            panic!("failed to insert entry (error={:?})", e);
+        }
+    }
+
+    // Remove entries in the middle of the probe sequence, and check that later ones are found.
+    if map.remove(&1) != Some(1) || map.remove(&2) != Some(2) {
+        panic!("failed to remove entries");
+    }
+    if map.get(&3) != Some(&3) || map.get(&0) != Some(&0) || map.get(&1).is_some() {
+        panic!("unexpected lookups after removal");
+    }
+
+    // Check if reinserting an existing key does not create a duplicate in a tombstone.
+    match map.insert(3, 30) {
+        Ok(Some(3)) => {},
+        Ok(old) => panic!("unexpected previous value (got={:?})", old),
+        Err((_, _, e)) => panic!("failed to replace entry (error={:?})", e),
+    }
+    if map.len() != 2 || map.iter().count() != 2 {
+        panic!("unexpected number of entries (got={})", map.len());
+    }
+}
+
+/// Attempts to create a hash map keyed by event descriptors on an unmanaged raw array.
+#[test]
+fn test_from_raw_array() {
+    let mut storage: [MaybeUninit<Bucket<EventDescriptor, usize>>; CAPACITY] =
+        [const { MaybeUninit::uninit() }; CAPACITY];
+    let array: RawArray<MaybeUninit<Bucket<EventDescriptor, usize>>> =
+        match unsafe { RawArray::from_raw_parts(storage.as_mut_ptr(), storage.len()) } {
+            Ok(array) => array,
+            Err(e) => panic!("failed to create array from raw parts (error={:?})", e),
+        };
+    let mut map: FixedHashMap<EventDescriptor, usize> =
+        FixedHashMap::from_raw_array(array, FnvBuildHasher::default());
+
+    let ev: EventDescriptor = EventDescriptor::new(7, Event::Interrupt(InterruptEvent::Interrupt3));
+    if let Err((_, _, e)) = map.insert(ev.clone(), 1) {
+        panic!("failed to insert entry (error={:?})", e);
+    }
+    if map.get(&ev) != Some(&1) {
+        panic!("failed to look up entry");
+    }
+}
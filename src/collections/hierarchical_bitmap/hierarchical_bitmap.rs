@@ -0,0 +1,307 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::{
+    collections::{
+        bitmap::Bitmap,
+        raw_array::RawArray,
+    },
+    error::{
+        Error,
+        ErrorCode,
+    },
+};
+
+//==================================================================================================
+// Structures
+//==================================================================================================
+
+///
+/// # Description
+///
+/// A two-level bitmap.
+///
+/// The leaf level holds the actual bits, whereas the summary level has one bit for each word of the
+/// leaf level, which is set if and only if that word is full. Searches for a free bit first look up
+/// the summary level, thus skipping over full words of the leaf level.
+///
+#[derive(Debug)]
+pub struct HierarchicalBitmap {
+    /// Summary level.
+    summary: Bitmap,
+    /// Leaf level.
+    leaf: Bitmap,
+}
+
+//==================================================================================================
+// Implementations
+//==================================================================================================
+
+impl HierarchicalBitmap {
+    ///
+    /// # Description
+    ///
+    /// Creates a new two-level bitmap with a given length. The bitmap is initialized with all bits
+    /// set to zero.
+    ///
+    /// # Parameters
+    ///
+    /// - `len`: Length of the bitmap in bits.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, a new two-level bitmap is returned. Upon failure, an error is returned
+    /// instead.
+    ///
+    pub fn new(len: usize) -> Result<Self, Error> {
+        let leaf: Bitmap = Bitmap::new(len)?;
+        let summary: Bitmap = Bitmap::new(Bitmap::words_for(len))?;
+
+        Ok(Self { summary, leaf })
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Creates a new two-level bitmap from raw arrays of words. The bitmap is initialized with all
+    /// bits set to zero.
+    ///
+    /// # Parameters
+    ///
+    /// - `summary`: Raw array to place the summary level in. It should be at least
+    ///   [`HierarchicalBitmap::summary_words_for()`] words long.
+    /// - `leaf`: Raw array to place the leaf level in. It should be at least
+    ///   [`Bitmap::words_for()`] words long.
+    /// - `len`: Length of the bitmap in bits.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, a new two-level bitmap is returned. Upon failure, an error is returned
+    /// instead.
+    ///
+    pub fn from_raw_arrays(
+        summary: RawArray<usize>,
+        leaf: RawArray<usize>,
+        len: usize,
+    ) -> Result<Self, Error> {
+        let leaf: Bitmap = Bitmap::from_raw_array(leaf, len)?;
+        let summary: Bitmap = Bitmap::from_raw_array(summary, Bitmap::words_for(len))?;
+
+        Ok(Self { summary, leaf })
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the number of words that are required to store the summary level of a two-level
+    /// bitmap with a given length.
+    ///
+    /// # Parameters
+    ///
+    /// - `len`: Length of the bitmap in bits.
+    ///
+    /// # Returns
+    ///
+    /// The number of words that are required to store the summary level.
+    ///
+    pub const fn summary_words_for(len: usize) -> usize {
+        Bitmap::words_for(Bitmap::words_for(len))
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the number of bits in the bitmap.
+    ///
+    /// # Returns
+    ///
+    /// The number of bits in the bitmap.
+    ///
+    pub fn number_of_bits(&self) -> usize {
+        self.leaf.number_of_bits()
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the number of bits that are set in the bitmap.
+    ///
+    /// # Returns
+    ///
+    /// The number of bits that are set in the bitmap.
+    ///
+    pub fn count_ones(&self) -> usize {
+        self.leaf.count_ones()
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the number of bits that are cleared in the bitmap.
+    ///
+    /// # Returns
+    ///
+    /// The number of bits that are cleared in the bitmap.
+    ///
+    pub fn count_zeros(&self) -> usize {
+        self.leaf.count_zeros()
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Allocates a bit in the bitmap. The lowest cleared bit is always the one that is allocated.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, the index of the allocated bit is returned. Upon failure, an error is returned
+    /// instead.
+    ///
+    pub fn alloc(&mut self) -> Result<usize, Error> {
+        // Find the first word of the leaf level that is not full.
+        let word: usize = match self.summary.find_next_clear(0) {
+            Some(word) => word,
+            None => return Err(Error::new(ErrorCode::OutOfMemory, "bitmap is full")),
+        };
+
+        // Find a free bit in that word.
+        let index: usize = match self.leaf.find_next_clear(word * Bitmap::WORD_BITS) {
+            Some(index) => index,
+            None => return Err(Error::new(ErrorCode::UncleanStructure, "inconsistent summary")),
+        };
+
+        self.set(index)?;
+
+        Ok(index)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Allocates a range of contiguous bits in the bitmap.
+    ///
+    /// # Parameters
+    ///
+    /// - `size`: Size of the range to allocate.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, the index of the allocated range is returned. Upon failure, an error is returned
+    /// instead.
+    ///
+    pub fn alloc_range(&mut self, size: usize) -> Result<usize, Error> {
+        let start: usize = self.leaf.alloc_range(size)?;
+        self.update_summary(start, size)?;
+        Ok(start)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Frees a range of contiguous bits in the bitmap.
+    ///
+    /// # Parameters
+    ///
+    /// - `start`: Index of the first bit in the range.
+    /// - `size`: Size of the range to free.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, `Ok(())` is returned. Upon failure, an error is returned instead and the bitmap
+    /// is left unmodified.
+    ///
+    pub fn free_range(&mut self, start: usize, size: usize) -> Result<(), Error> {
+        self.leaf.free_range(start, size)?;
+        self.update_summary(start, size)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Sets a bit at a given index in the bitmap.
+    ///
+    /// # Parameters
+    ///
+    /// - `index`: Index of the bit to set.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, `Ok(())` is returned. Upon failure, an error is returned instead.
+    ///
+    pub fn set(&mut self, index: usize) -> Result<(), Error> {
+        self.leaf.set(index)?;
+        self.update_summary(index, 1)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Clears a bit at a given index in the bitmap.
+    ///
+    /// # Parameters
+    ///
+    /// - `index`: Index of the bit to clear.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, `Ok(())` is returned. Upon failure, an error is returned instead.
+    ///
+    pub fn clear(&mut self, index: usize) -> Result<(), Error> {
+        self.leaf.clear(index)?;
+        self.update_summary(index, 1)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Tests a bit at a given index in the bitmap.
+    ///
+    /// # Parameters
+    ///
+    /// - `index`: Index of the bit to test.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, `Ok(true)` is returned if the bit is set, `Ok(false)` is returned otherwise.
+    /// Upon failure, an error is returned instead.
+    ///
+    pub fn test(&self, index: usize) -> Result<bool, Error> {
+        self.leaf.test(index)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Updates the summary level for all words of the leaf level that overlap with a range of bits.
+    ///
+    /// # Parameters
+    ///
+    /// - `start`: Index of the first bit in the range.
+    /// - `size`: Size of the range.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, `Ok(())` is returned. Upon failure, an error is returned instead.
+    ///
+    fn update_summary(&mut self, start: usize, size: usize) -> Result<(), Error> {
+        let first: usize = start / Bitmap::WORD_BITS;
+        let last: usize = (start + size - 1) / Bitmap::WORD_BITS;
+
+        for word in first..=last {
+            let full: bool = self.leaf.is_word_full(word);
+            if full != self.summary.test(word)? {
+                if full {
+                    self.summary.set(word)?;
+                } else {
+                    self.summary.clear(word)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,17 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Modules
+//==================================================================================================
+
+mod hierarchical_bitmap;
+
+#[cfg(test)]
+mod test;
+
+//==================================================================================================
+// Exports
+//==================================================================================================
+
+pub use hierarchical_bitmap::*;
@@ -0,0 +1,138 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::{
+    collections::{
+        raw_array::RawArray,
+        Bitmap,
+        HierarchicalBitmap,
+    },
+    error::{
+        Error,
+        ErrorCode,
+    },
+};
+
+//==================================================================================================
+// Unit Tests
+//==================================================================================================
+
+/// Length of bitmaps used in tests, which spans multiple words and ends in a partial word.
+const LENGTH: usize = 4 * usize::BITS as usize + 9;
+
+/// Helper test function that creates a [`HierarchicalBitmap`] from raw arrays.
+fn test_helper_create_bitmap_from_raw_arrays(
+    summary: &mut [usize],
+    leaf: &mut [usize],
+    len: usize,
+) -> Result<HierarchicalBitmap, Error> {
+    let summary: RawArray<usize> =
+        unsafe { RawArray::from_raw_parts(summary.as_mut_ptr(), summary.len())? };
+    let leaf: RawArray<usize> = unsafe { RawArray::from_raw_parts(leaf.as_mut_ptr(), leaf.len())? };
+
+    HierarchicalBitmap::from_raw_arrays(summary, leaf, len)
+}
+
+/// Attempts to allocate and clear all bits in a [`HierarchicalBitmap`].
+#[test]
+fn test_alloc_and_clear_all_bits() {
+    let mut summary: [usize; HierarchicalBitmap::summary_words_for(LENGTH)] =
+        [usize::MAX; HierarchicalBitmap::summary_words_for(LENGTH)];
+    let mut leaf: [usize; Bitmap::words_for(LENGTH)] = [usize::MAX; Bitmap::words_for(LENGTH)];
+
+    // Create bitmap.
+    let mut bitmap: HierarchicalBitmap =
+        match test_helper_create_bitmap_from_raw_arrays(&mut summary, &mut leaf, LENGTH) {
+            Ok(bitmap) => bitmap,
+            Err(_) => panic!("failed to create bitmap"),
+        };
+
+    // Allocate all bits.
+    for i in 0..LENGTH {
+        match bitmap.alloc() {
+            Ok(index) if index == i => {},
+            Ok(index) => panic!("unexpected index (expected={}, got={})", i, index),
+            Err(_) => panic!("failed to allocate bit at index {}", i),
+        }
+    }
+
+    // Check if the bitmap is full.
+    match bitmap.alloc() {
+        Ok(_) => panic!("allocated bit in a full bitmap"),
+        Err(e) if e.code == ErrorCode::OutOfMemory => {},
+        Err(e) => panic!("unexpected error code (error={:?})", e),
+    }
+
+    // Clear all bits.
+    for i in 0..LENGTH {
+        if bitmap.clear(i).is_err() {
+            panic!("failed to clear bit at index {}", i);
+        }
+    }
+
+    // Check if all bits were cleared.
+    if bitmap.count_ones() != 0 {
+        panic!("unexpected usage (expected=0, got={})", bitmap.count_ones());
+    }
+}
+
+/// Attempts to allocate bits in a [`HierarchicalBitmap`] whose first words are full.
+#[test]
+fn test_alloc_skips_full_words() {
+    let word_bits: usize = usize::BITS as usize;
+
+    // Create bitmap.
+    let mut bitmap: HierarchicalBitmap = match HierarchicalBitmap::new(LENGTH) {
+        Ok(bitmap) => bitmap,
+        Err(_) => panic!("failed to create bitmap"),
+    };
+
+    // Fill the first two words with a range that spans them.
+    if bitmap.alloc_range(2 * word_bits).is_err() {
+        panic!("failed to allocate range");
+    }
+
+    // Check if the next allocation lands past the full words.
+    match bitmap.alloc() {
+        Ok(index) if index == 2 * word_bits => {},
+        Ok(index) => panic!("unexpected index (expected={}, got={})", 2 * word_bits, index),
+        Err(_) => panic!("failed to allocate bit"),
+    }
+
+    // Free a bit in the first word and check if it gets allocated next.
+    if bitmap.clear(5).is_err() {
+        panic!("failed to clear bit at index 5");
+    }
+    match bitmap.alloc() {
+        Ok(5) => {},
+        Ok(index) => panic!("unexpected index (expected=5, got={})", index),
+        Err(_) => panic!("failed to allocate bit"),
+    }
+
+    // Free the range and check if the lowest bit gets allocated next.
+    if bitmap.free_range(0, 2 * word_bits).is_err() {
+        panic!("failed to free range");
+    }
+    match bitmap.alloc() {
+        Ok(0) => {},
+        Ok(index) => panic!("unexpected index (expected=0, got={})", index),
+        Err(_) => panic!("failed to allocate bit"),
+    }
+}
+
+/// Attempts to create a [`HierarchicalBitmap`] from raw arrays that are too small.
+#[test]
+fn test_from_raw_arrays_too_small() {
+    let mut summary: [usize; 1] = [0; 1];
+    let mut leaf: [usize; 1] = [0; 1];
+
+    match test_helper_create_bitmap_from_raw_arrays(&mut summary, &mut leaf, LENGTH) {
+        Ok(_) => panic!("created bitmap from raw arrays that are too small"),
+        Err(e) if e.code == ErrorCode::InvalidArgument => {},
+        Err(e) => panic!("unexpected error code (error={:?})", e),
+    }
+}
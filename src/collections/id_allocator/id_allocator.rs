@@ -0,0 +1,338 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::{
+    collections::{
+        bitmap::Bitmap,
+        ring_buffer::RingBuffer,
+    },
+    error::{
+        Error,
+        ErrorCode,
+    },
+};
+use ::core::marker::PhantomData;
+
+//==================================================================================================
+// Structures
+//==================================================================================================
+
+///
+/// # Description
+///
+/// An allocator of identifiers of type `Id`, such as process and thread identifiers.
+///
+/// Identifiers range from a base value up to a fixed number of identifiers. Some of them may be
+/// reserved upfront, so that they are never handed out. Freed identifiers may optionally be kept
+/// out of circulation for a while, so that stale copies of an identifier are unlikely to refer to
+/// a new owner.
+///
+#[derive(Debug)]
+pub struct IdAllocator<Id> {
+    /// Bitmap of identifiers that are allocated, reserved or pending reuse.
+    ids: Bitmap,
+    /// Value of the first identifier.
+    base: usize,
+    /// Identifiers that were freed, but are not yet available for reuse.
+    quarantine: Option<Quarantine>,
+    /// Marker for the type of identifiers.
+    _marker: PhantomData<Id>,
+}
+
+///
+/// # Description
+///
+/// A queue of identifiers that were freed, but are not yet available for reuse.
+///
+#[derive(Debug)]
+struct Quarantine {
+    /// Indexes of identifiers in the order they were freed.
+    queue: RingBuffer<usize>,
+    /// Bitmap of identifiers in the queue.
+    pending: Bitmap,
+}
+
+//==================================================================================================
+// Implementations
+//==================================================================================================
+
+impl<Id> IdAllocator<Id>
+where
+    Id: TryFrom<usize> + Into<usize>,
+{
+    ///
+    /// # Description
+    ///
+    /// Creates a new identifier allocator with all identifiers free.
+    ///
+    /// # Parameters
+    ///
+    /// - `base`: Value of the first identifier.
+    /// - `len`: Number of identifiers.
+    /// - `reuse_delay`: Number of identifiers that are freed before a freed identifier is handed
+    ///   out again. If zero, freed identifiers are available for reuse right away.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, a new identifier allocator is returned. Upon failure, an error is returned
+    /// instead.
+    ///
+    pub fn new(base: usize, len: usize, reuse_delay: usize) -> Result<Self, Error> {
+        // Check if the last identifier is valid.
+        match base.checked_add(len).and_then(|end| end.checked_sub(1)) {
+            Some(last) if Id::try_from(last).is_ok() => {},
+            _ => return Err(Error::new(ErrorCode::InvalidArgument, "invalid identifier range")),
+        }
+
+        let quarantine: Option<Quarantine> = if reuse_delay == 0 {
+            None
+        } else {
+            Some(Quarantine {
+                queue: RingBuffer::new(reuse_delay)?,
+                pending: Bitmap::new(len)?,
+            })
+        };
+
+        Ok(Self {
+            ids: Bitmap::new(len)?,
+            base,
+            quarantine,
+            _marker: PhantomData,
+        })
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the number of identifiers that are managed by the identifier allocator.
+    ///
+    /// # Returns
+    ///
+    /// The number of identifiers that are managed by the identifier allocator.
+    ///
+    pub fn capacity(&self) -> usize {
+        self.ids.number_of_bits()
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the number of identifiers that can still be allocated. This includes identifiers
+    /// that are pending reuse.
+    ///
+    /// # Returns
+    ///
+    /// The number of identifiers that can still be allocated.
+    ///
+    pub fn available(&self) -> usize {
+        let pending: usize = match &self.quarantine {
+            Some(quarantine) => quarantine.queue.len(),
+            None => 0,
+        };
+        self.ids.count_zeros() + pending
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Reserves an identifier, so that it is never handed out.
+    ///
+    /// # Parameters
+    ///
+    /// - `id`: Identifier to reserve.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, empty is returned. Upon failure, an error is returned instead.
+    ///
+    pub fn reserve(&mut self, id: Id) -> Result<(), Error> {
+        self.reserve_range(id, 1)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Reserves a range of contiguous identifiers, so that they are never handed out.
+    ///
+    /// # Parameters
+    ///
+    /// - `first`: First identifier in the range.
+    /// - `count`: Number of identifiers in the range.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, empty is returned. Upon failure, an error is returned instead and no
+    /// identifier is reserved.
+    ///
+    pub fn reserve_range(&mut self, first: Id, count: usize) -> Result<(), Error> {
+        let index: usize = self.index(first)?;
+        match self.ids.set_range(index, count) {
+            Ok(()) => Ok(()),
+            Err(e) if e.code == ErrorCode::ResourceBusy => {
+                Err(Error::new(ErrorCode::ResourceBusy, "identifier is already in use"))
+            },
+            Err(_) => Err(Error::new(ErrorCode::InvalidArgument, "invalid identifier range")),
+        }
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Allocates an identifier. The lowest identifier that is available is always the one that is
+    /// allocated. If none is available, the identifier that has been pending reuse for the longest
+    /// time is handed out instead.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, the allocated identifier is returned. Upon failure, an error is returned
+    /// instead.
+    ///
+    pub fn alloc(&mut self) -> Result<Id, Error> {
+        let index: usize = match self.ids.alloc() {
+            Ok(index) => index,
+            Err(_) => match self.release_oldest() {
+                Some(index) => {
+                    self.ids.set(index)?;
+                    index
+                },
+                None => {
+                    return Err(Error::new(ErrorCode::TooManyUsers, "no identifiers available"))
+                },
+            },
+        };
+
+        // Convert index to identifier.
+        match Id::try_from(self.base + index) {
+            Ok(id) => Ok(id),
+            Err(_) => {
+                self.ids.clear(index)?;
+                Err(Error::new(ErrorCode::InvalidArgument, "invalid identifier"))
+            },
+        }
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Frees an identifier.
+    ///
+    /// # Parameters
+    ///
+    /// - `id`: Identifier to free.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, empty is returned. Upon failure, an error is returned instead.
+    ///
+    pub fn free(&mut self, id: Id) -> Result<(), Error> {
+        let index: usize = self.index(id)?;
+
+        // Check if the identifier is allocated.
+        if !self.is_allocated_index(index)? {
+            return Err(Error::new(ErrorCode::InvalidArgument, "identifier is not allocated"));
+        }
+
+        // Release the identifier right away, if delayed reuse is disabled.
+        if self.quarantine.is_none() {
+            return self.ids.clear(index);
+        }
+
+        // Make room for the identifier in the quarantine, by releasing the oldest one.
+        if let Some(quarantine) = &self.quarantine {
+            if quarantine.queue.is_full() {
+                self.release_oldest();
+            }
+        }
+        if let Some(quarantine) = &mut self.quarantine {
+            quarantine.pending.set(index)?;
+            if let Err((_, e)) = quarantine.queue.push(index) {
+                return Err(e);
+            }
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Checks if an identifier is allocated or reserved.
+    ///
+    /// # Parameters
+    ///
+    /// - `id`: Identifier to check.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, `Ok(true)` is returned if the identifier is allocated or reserved, `Ok(false)`
+    /// is returned otherwise. Upon failure, an error is returned instead.
+    ///
+    pub fn is_allocated(&self, id: Id) -> Result<bool, Error> {
+        self.is_allocated_index(self.index(id)?)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Checks if the identifier at a given index is allocated or reserved.
+    ///
+    /// # Parameters
+    ///
+    /// - `index`: Index of the identifier to check.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, `Ok(true)` is returned if the identifier is allocated or reserved, `Ok(false)`
+    /// is returned otherwise. Upon failure, an error is returned instead.
+    ///
+    fn is_allocated_index(&self, index: usize) -> Result<bool, Error> {
+        let pending: bool = match &self.quarantine {
+            Some(quarantine) => quarantine.pending.test(index)?,
+            None => false,
+        };
+        Ok(self.ids.test(index)? && !pending)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Releases the identifier that has been pending reuse for the longest time.
+    ///
+    /// # Returns
+    ///
+    /// The index of the released identifier, if any.
+    ///
+    fn release_oldest(&mut self) -> Option<usize> {
+        let quarantine: &mut Quarantine = self.quarantine.as_mut()?;
+        let index: usize = quarantine.queue.pop().ok()?;
+        quarantine.pending.clear(index).ok()?;
+        self.ids.clear(index).ok()?;
+        Some(index)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Converts an identifier to its index in the identifier allocator.
+    ///
+    /// # Parameters
+    ///
+    /// - `id`: Identifier to convert.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, the index of the identifier is returned. Upon failure, an error is returned
+    /// instead.
+    ///
+    fn index(&self, id: Id) -> Result<usize, Error> {
+        let raw: usize = id.into();
+        match raw.checked_sub(self.base) {
+            Some(index) if index < self.capacity() => Ok(index),
+            _ => Err(Error::new(ErrorCode::InvalidArgument, "invalid identifier")),
+        }
+    }
+}
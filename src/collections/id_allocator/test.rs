@@ -0,0 +1,138 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::{
+    collections::IdAllocator,
+    error::ErrorCode,
+    pm::{
+        ProcessIdentifier,
+        ThreadIdentifier,
+    },
+};
+
+//==================================================================================================
+// Unit Tests
+//==================================================================================================
+
+/// Number of identifiers used in tests.
+const NUM_IDS: usize = 8;
+
+/// Attempts to allocate all process identifiers, skipping reserved ones.
+#[test]
+fn test_alloc_with_reserved() {
+    let mut pids: IdAllocator<ProcessIdentifier> = match IdAllocator::new(0, NUM_IDS, 0) {
+        Ok(pids) => pids,
+        Err(e) => panic!("failed to create identifier allocator (error={:?})", e),
+    };
+    if pids.reserve(ProcessIdentifier::KERNEL).is_err()
+        || pids.reserve(ProcessIdentifier::PROCD).is_err()
+    {
+        panic!("failed to reserve identifiers");
+    }
+
+    // Allocate all identifiers and check that reserved ones are never handed out.
+    for expected in 2..NUM_IDS {
+        match pids.alloc() {
+            Ok(pid) if usize::from(pid) == expected => {},
+            Ok(pid) => panic!("unexpected identifier (expected={}, got={:?})", expected, pid),
+            Err(e) => panic!("failed to allocate identifier (error={:?})", e),
+        }
+    }
+    match pids.alloc() {
+        Ok(pid) => panic!("allocated identifier {:?} from an exhausted allocator", pid),
+        Err(e) if e.code == ErrorCode::TooManyUsers => {},
+        Err(e) => panic!("unexpected error code (error={:?})", e),
+    }
+
+    // Free an identifier and check that it is reused right away.
+    let pid: ProcessIdentifier = ProcessIdentifier::from(5);
+    if let Err(e) = pids.free(pid) {
+        panic!("failed to free identifier (error={:?})", e);
+    }
+    match pids.free(pid) {
+        Ok(_) => panic!("freed identifier twice"),
+        Err(e) if e.code == ErrorCode::InvalidArgument => {},
+        Err(e) => panic!("unexpected error code (error={:?})", e),
+    }
+    match pids.alloc() {
+        Ok(reused) if reused == pid => {},
+        Ok(reused) => panic!("unexpected identifier (expected={:?}, got={:?})", pid, reused),
+        Err(e) => panic!("failed to allocate identifier (error={:?})", e),
+    }
+}
+
+/// Attempts to free identifiers with delayed reuse and check the order in which they are reused.
+#[test]
+fn test_delayed_reuse() {
+    const BASE: usize = 100;
+    let mut tids: IdAllocator<ThreadIdentifier> = match IdAllocator::new(BASE, NUM_IDS, 2) {
+        Ok(tids) => tids,
+        Err(e) => panic!("failed to create identifier allocator (error={:?})", e),
+    };
+    for _ in 0..NUM_IDS {
+        if let Err(e) = tids.alloc() {
+            panic!("failed to allocate identifier (error={:?})", e);
+        }
+    }
+
+    // Free three identifiers. The first one is released as soon as the third one is freed.
+    for raw in [BASE + 1, BASE + 2, BASE + 3] {
+        if let Err(e) = tids.free(ThreadIdentifier::from(raw)) {
+            panic!("failed to free identifier (error={:?})", e);
+        }
+    }
+    match tids.is_allocated(ThreadIdentifier::from(BASE + 2)) {
+        Ok(false) => {},
+        Ok(true) => panic!("identifier pending reuse is reported as allocated"),
+        Err(e) => panic!("failed to check identifier (error={:?})", e),
+    }
+    if tids.available() != 3 {
+        panic!("unexpected number of available identifiers (got={})", tids.available());
+    }
+
+    // Check that identifiers pending reuse are handed out oldest first, once others run out.
+    for expected in [BASE + 1, BASE + 2, BASE + 3] {
+        match tids.alloc() {
+            Ok(tid) if usize::from(tid) == expected => {},
+            Ok(tid) => panic!("unexpected identifier (expected={}, got={:?})", expected, tid),
+            Err(e) => panic!("failed to allocate identifier (error={:?})", e),
+        }
+    }
+    match tids.alloc() {
+        Ok(tid) => panic!("allocated identifier {:?} from an exhausted allocator", tid),
+        Err(e) if e.code == ErrorCode::TooManyUsers => {},
+        Err(e) => panic!("unexpected error code (error={:?})", e),
+    }
+}
+
+/// Attempts to use identifiers that lie outside the range of an [`IdAllocator`].
+#[test]
+fn test_out_of_range() {
+    let mut ids: IdAllocator<usize> = match IdAllocator::new(16, NUM_IDS, 0) {
+        Ok(ids) => ids,
+        Err(e) => panic!("failed to create identifier allocator (error={:?})", e),
+    };
+    for id in [15, 16 + NUM_IDS] {
+        match ids.free(id) {
+            Ok(_) => panic!("freed identifier {} that is out of range", id),
+            Err(e) if e.code == ErrorCode::InvalidArgument => {},
+            Err(e) => panic!("unexpected error code (error={:?})", e),
+        }
+    }
+    match ids.reserve_range(16 + NUM_IDS - 1, 2) {
+        Ok(_) => panic!("reserved range that is out of range"),
+        Err(e) if e.code == ErrorCode::InvalidArgument => {},
+        Err(e) => panic!("unexpected error code (error={:?})", e),
+    }
+
+    // Check that identifiers that do not fit the type are rejected upfront.
+    match IdAllocator::<ProcessIdentifier>::new(u32::MAX as usize, 2, 0) {
+        Ok(_) => panic!("created identifier allocator with invalid range"),
+        Err(e) if e.code == ErrorCode::InvalidArgument => {},
+        Err(e) => panic!("unexpected error code (error={:?})", e),
+    }
+}
@@ -0,0 +1,444 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::{
+    collections::intrusive_list::{
+        ListAdapter,
+        ListLink,
+    },
+    error::{
+        Error,
+        ErrorCode,
+    },
+};
+use ::core::{
+    marker::PhantomData,
+    ptr::NonNull,
+};
+
+//==================================================================================================
+// Structures
+//==================================================================================================
+
+///
+/// # Description
+///
+/// An intrusive doubly linked list.
+///
+/// Link fields are embedded in elements, and they are located through the adapter `A`. Therefore,
+/// the list never allocates memory, and elements may live anywhere, such as in a [`Slab`].
+/// The list does not own its elements: it merely refers to them while they are linked.
+///
+/// [`Slab`]: crate::collections::Slab
+///
+#[derive(Debug)]
+pub struct IntrusiveList<A: ListAdapter> {
+    /// First link in the list.
+    head: Option<NonNull<ListLink>>,
+    /// Last link in the list.
+    tail: Option<NonNull<ListLink>>,
+    /// Number of elements in the list.
+    len: usize,
+    /// Marker for the adapter.
+    _marker: PhantomData<A>,
+}
+
+///
+/// # Description
+///
+/// A cursor over an [`IntrusiveList`], which may remove and insert elements while traversing it.
+///
+#[derive(Debug)]
+pub struct ListCursor<'a, A: ListAdapter> {
+    /// Underlying list.
+    list: &'a mut IntrusiveList<A>,
+    /// Link of the element that the cursor points to.
+    current: Option<NonNull<ListLink>>,
+}
+
+//==================================================================================================
+// Implementations
+//==================================================================================================
+
+impl<A: ListAdapter> IntrusiveList<A> {
+    ///
+    /// # Description
+    ///
+    /// Creates a new empty list.
+    ///
+    /// # Returns
+    ///
+    /// A new empty list.
+    ///
+    pub const fn new() -> Self {
+        Self {
+            head: None,
+            tail: None,
+            len: 0,
+            _marker: PhantomData,
+        }
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the number of elements in the list.
+    ///
+    /// # Returns
+    ///
+    /// The number of elements in the list.
+    ///
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Checks if the list is empty.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the list has no elements, `false` otherwise.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the first element of the list.
+    ///
+    /// # Returns
+    ///
+    /// The first element of the list, if any.
+    ///
+    pub fn front(&self) -> Option<NonNull<A::Value>> {
+        // Safety: links in the list are embedded in elements.
+        self.head.map(|link| unsafe { A::from_link(link) })
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the last element of the list.
+    ///
+    /// # Returns
+    ///
+    /// The last element of the list, if any.
+    ///
+    pub fn back(&self) -> Option<NonNull<A::Value>> {
+        // Safety: links in the list are embedded in elements.
+        self.tail.map(|link| unsafe { A::from_link(link) })
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Appends an element to the back of the list.
+    ///
+    /// # Parameters
+    ///
+    /// - `value`: Element to append.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, empty is returned. Upon failure, an error is returned instead.
+    ///
+    /// # Safety
+    ///
+    /// This function is unsafe because it assumes that `value` is valid, and that it remains valid
+    /// and is not moved until it is removed from the list.
+    ///
+    pub unsafe fn push_back(&mut self, value: NonNull<A::Value>) -> Result<(), Error> {
+        let link: NonNull<ListLink> = Self::link_of(value)?;
+        self.link_before(link, None);
+        Ok(())
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Prepends an element to the front of the list.
+    ///
+    /// # Parameters
+    ///
+    /// - `value`: Element to prepend.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, empty is returned. Upon failure, an error is returned instead.
+    ///
+    /// # Safety
+    ///
+    /// This function is unsafe because it assumes that `value` is valid, and that it remains valid
+    /// and is not moved until it is removed from the list.
+    ///
+    pub unsafe fn push_front(&mut self, value: NonNull<A::Value>) -> Result<(), Error> {
+        let link: NonNull<ListLink> = Self::link_of(value)?;
+        self.link_before(link, self.head);
+        Ok(())
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Removes the first element of the list.
+    ///
+    /// # Returns
+    ///
+    /// The first element of the list, if any.
+    ///
+    pub fn pop_front(&mut self) -> Option<NonNull<A::Value>> {
+        let link: NonNull<ListLink> = self.head?;
+        self.unlink(link);
+        // Safety: links in the list are embedded in elements.
+        Some(unsafe { A::from_link(link) })
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Removes the last element of the list.
+    ///
+    /// # Returns
+    ///
+    /// The last element of the list, if any.
+    ///
+    pub fn pop_back(&mut self) -> Option<NonNull<A::Value>> {
+        let link: NonNull<ListLink> = self.tail?;
+        self.unlink(link);
+        // Safety: links in the list are embedded in elements.
+        Some(unsafe { A::from_link(link) })
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Removes an element from the list.
+    ///
+    /// # Parameters
+    ///
+    /// - `value`: Element to remove.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, empty is returned. Upon failure, an error is returned instead.
+    ///
+    /// # Safety
+    ///
+    /// This function is unsafe because it assumes that `value` is valid, and that it is either in
+    /// this list or in no list at all.
+    ///
+    pub unsafe fn remove(&mut self, value: NonNull<A::Value>) -> Result<(), Error> {
+        let link: &ListLink = A::link(value.as_ref());
+
+        // Check if the element is not in a list.
+        if !link.is_linked() {
+            return Err(Error::new(ErrorCode::InvalidArgument, "element is not linked"));
+        }
+
+        self.unlink(NonNull::from(link));
+        Ok(())
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns a cursor that points to the first element of the list.
+    ///
+    /// # Returns
+    ///
+    /// A cursor that points to the first element of the list.
+    ///
+    pub fn cursor_front(&mut self) -> ListCursor<'_, A> {
+        ListCursor {
+            current: self.head,
+            list: self,
+        }
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the link of an element that is not in any list.
+    ///
+    /// # Parameters
+    ///
+    /// - `value`: Target element.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, the link of the target element is returned. Upon failure, an error is returned
+    /// instead.
+    ///
+    /// # Safety
+    ///
+    /// This function is unsafe because it assumes that `value` is valid.
+    ///
+    unsafe fn link_of(value: NonNull<A::Value>) -> Result<NonNull<ListLink>, Error> {
+        let link: &ListLink = A::link(value.as_ref());
+
+        // Check if the element is already in a list.
+        if link.is_linked() {
+            return Err(Error::new(ErrorCode::ResourceBusy, "element is already linked"));
+        }
+
+        Ok(NonNull::from(link))
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Links a link into the list, before another one.
+    ///
+    /// # Parameters
+    ///
+    /// - `link`: Link to insert.
+    /// - `next`: Link before which to insert. If `None`, the link is inserted at the back.
+    ///
+    fn link_before(&mut self, link: NonNull<ListLink>, next: Option<NonNull<ListLink>>) {
+        // Safety: all links that are handled here are embedded in valid elements.
+        let new: &ListLink = unsafe { link.as_ref() };
+        let prev: Option<NonNull<ListLink>> = match next {
+            Some(next) => unsafe { next.as_ref().prev.get() },
+            None => self.tail,
+        };
+
+        new.prev.set(prev);
+        new.next.set(next);
+        new.linked.set(true);
+
+        // Safety: all links that are handled here are embedded in valid elements.
+        match prev {
+            Some(prev) => unsafe { prev.as_ref().next.set(Some(link)) },
+            None => self.head = Some(link),
+        }
+        match next {
+            Some(next) => unsafe { next.as_ref().prev.set(Some(link)) },
+            None => self.tail = Some(link),
+        }
+
+        self.len += 1;
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Unlinks a link from the list.
+    ///
+    /// # Parameters
+    ///
+    /// - `link`: Link to remove.
+    ///
+    fn unlink(&mut self, link: NonNull<ListLink>) {
+        // Safety: all links that are handled here are embedded in valid elements.
+        let old: &ListLink = unsafe { link.as_ref() };
+        let prev: Option<NonNull<ListLink>> = old.prev.get();
+        let next: Option<NonNull<ListLink>> = old.next.get();
+
+        // Safety: all links that are handled here are embedded in valid elements.
+        match prev {
+            Some(prev) => unsafe { prev.as_ref().next.set(next) },
+            None => self.head = next,
+        }
+        match next {
+            Some(next) => unsafe { next.as_ref().prev.set(prev) },
+            None => self.tail = prev,
+        }
+
+        old.prev.set(None);
+        old.next.set(None);
+        old.linked.set(false);
+        self.len -= 1;
+    }
+}
+
+impl<A: ListAdapter> Default for IntrusiveList<A> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A: ListAdapter> Drop for IntrusiveList<A> {
+    fn drop(&mut self) {
+        // Unlink elements that are left in the list, so that they can be linked again.
+        while self.pop_front().is_some() {}
+    }
+}
+
+impl<A: ListAdapter> ListCursor<'_, A> {
+    ///
+    /// # Description
+    ///
+    /// Returns the element that the cursor points to.
+    ///
+    /// # Returns
+    ///
+    /// The element that the cursor points to, or `None` if the cursor is past the end of the list.
+    ///
+    pub fn current(&self) -> Option<NonNull<A::Value>> {
+        // Safety: links in the list are embedded in elements.
+        self.current.map(|link| unsafe { A::from_link(link) })
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Moves the cursor to the next element of the list. It has no effect if the cursor is past the
+    /// end of the list.
+    ///
+    pub fn move_next(&mut self) {
+        if let Some(link) = self.current {
+            // Safety: links in the list are embedded in valid elements.
+            self.current = unsafe { link.as_ref().next.get() };
+        }
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Removes the element that the cursor points to, and moves the cursor to the next element.
+    ///
+    /// # Returns
+    ///
+    /// The removed element, or `None` if the cursor is past the end of the list.
+    ///
+    pub fn remove_current(&mut self) -> Option<NonNull<A::Value>> {
+        let link: NonNull<ListLink> = self.current?;
+        self.move_next();
+        self.list.unlink(link);
+        // Safety: links in the list are embedded in elements.
+        Some(unsafe { A::from_link(link) })
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Inserts an element before the one that the cursor points to. If the cursor is past the end
+    /// of the list, the element is appended to the back of the list.
+    ///
+    /// # Parameters
+    ///
+    /// - `value`: Element to insert.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, empty is returned. Upon failure, an error is returned instead.
+    ///
+    /// # Safety
+    ///
+    /// This function is unsafe because it assumes that `value` is valid, and that it remains valid
+    /// and is not moved until it is removed from the list.
+    ///
+    pub unsafe fn insert_before(&mut self, value: NonNull<A::Value>) -> Result<(), Error> {
+        let link: NonNull<ListLink> = IntrusiveList::<A>::link_of(value)?;
+        self.list.link_before(link, self.current);
+        Ok(())
+    }
+}
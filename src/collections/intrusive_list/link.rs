@@ -0,0 +1,122 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use ::core::{
+    cell::Cell,
+    ptr::NonNull,
+};
+
+//==================================================================================================
+// Structures
+//==================================================================================================
+
+///
+/// # Description
+///
+/// Link fields that are embedded in elements of an [`IntrusiveList`](super::IntrusiveList).
+///
+/// An element may be linked in at most one list through each of its link fields.
+///
+#[derive(Debug, Default)]
+pub struct ListLink {
+    /// Previous link in the list.
+    pub(super) prev: Cell<Option<NonNull<ListLink>>>,
+    /// Next link in the list.
+    pub(super) next: Cell<Option<NonNull<ListLink>>>,
+    /// Whether the link is in a list.
+    pub(super) linked: Cell<bool>,
+}
+
+//==================================================================================================
+// Traits
+//==================================================================================================
+
+///
+/// # Description
+///
+/// An adapter that locates the [`ListLink`] of elements of an
+/// [`IntrusiveList`](super::IntrusiveList).
+///
+/// # Safety
+///
+/// Implementations must ensure that [`ListAdapter::from_link()`] is the inverse of
+/// [`ListAdapter::link()`], that is, it returns the element that embeds a given link.
+///
+pub unsafe trait ListAdapter {
+    /// Type of elements.
+    type Value;
+
+    ///
+    /// # Description
+    ///
+    /// Returns the link that is embedded in an element.
+    ///
+    /// # Parameters
+    ///
+    /// - `value`: Target element.
+    ///
+    /// # Returns
+    ///
+    /// The link that is embedded in the target element.
+    ///
+    fn link(value: &Self::Value) -> &ListLink;
+
+    ///
+    /// # Description
+    ///
+    /// Returns the element that embeds a link.
+    ///
+    /// # Parameters
+    ///
+    /// - `link`: Target link.
+    ///
+    /// # Returns
+    ///
+    /// The element that embeds the target link.
+    ///
+    /// # Safety
+    ///
+    /// This function is unsafe because it assumes that `link` is embedded in an element.
+    ///
+    unsafe fn from_link(link: NonNull<ListLink>) -> NonNull<Self::Value>;
+}
+
+//==================================================================================================
+// Implementations
+//==================================================================================================
+
+impl ListLink {
+    ///
+    /// # Description
+    ///
+    /// Creates a new link that is not in any list.
+    ///
+    /// # Returns
+    ///
+    /// A new link that is not in any list.
+    ///
+    pub const fn new() -> Self {
+        Self {
+            prev: Cell::new(None),
+            next: Cell::new(None),
+            linked: Cell::new(false),
+        }
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Checks if the link is in a list.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the link is in a list, `false` otherwise.
+    ///
+    pub fn is_linked(&self) -> bool {
+        self.linked.get()
+    }
+}
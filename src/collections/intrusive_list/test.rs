@@ -0,0 +1,159 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::{
+    collections::{
+        IntrusiveList,
+        ListAdapter,
+        ListCursor,
+        ListLink,
+    },
+    error::ErrorCode,
+};
+use ::alloc::vec::Vec;
+use ::core::{
+    mem,
+    ptr::NonNull,
+};
+
+//==================================================================================================
+// Unit Tests
+//==================================================================================================
+
+/// Number of elements used in tests.
+const NUM_THREADS: usize = 8;
+
+/// An element that is stored in lists used in tests.
+struct Thread {
+    /// Identifier of the thread.
+    id: usize,
+    /// Link in a run queue.
+    link: ListLink,
+}
+
+/// Adapter for run queues of threads.
+struct RunQueueAdapter;
+
+unsafe impl ListAdapter for RunQueueAdapter {
+    type Value = Thread;
+
+    fn link(value: &Thread) -> &ListLink {
+        &value.link
+    }
+
+    unsafe fn from_link(link: NonNull<ListLink>) -> NonNull<Thread> {
+        link.byte_sub(mem::offset_of!(Thread, link)).cast()
+    }
+}
+
+/// Helper test function that creates threads.
+fn test_helper_create_threads() -> Vec<Thread> {
+    (0..NUM_THREADS)
+        .map(|id| Thread {
+            id,
+            link: ListLink::new(),
+        })
+        .collect()
+}
+
+/// Helper test function that collects identifiers of threads in a list.
+fn test_helper_collect_ids(list: &mut IntrusiveList<RunQueueAdapter>) -> Vec<usize> {
+    let mut ids: Vec<usize> = Vec::new();
+    let mut cursor: ListCursor<'_, RunQueueAdapter> = list.cursor_front();
+    while let Some(thread) = cursor.current() {
+        ids.push(unsafe { thread.as_ref().id });
+        cursor.move_next();
+    }
+    ids
+}
+
+/// Attempts to push, remove and pop elements to and from an [`IntrusiveList`].
+#[test]
+fn test_push_remove_pop() {
+    let threads: Vec<Thread> = test_helper_create_threads();
+    let mut list: IntrusiveList<RunQueueAdapter> = IntrusiveList::new();
+
+    for thread in threads.iter() {
+        if let Err(e) = unsafe { list.push_back(NonNull::from(thread)) } {
+            panic!("failed to push element (error={:?})", e);
+        }
+    }
+
+    // Check if an element cannot be linked twice.
+    match unsafe { list.push_front(NonNull::from(&threads[0])) } {
+        Ok(_) => panic!("pushed element that is already linked"),
+        Err(e) if e.code == ErrorCode::ResourceBusy => {},
+        Err(e) => panic!("unexpected error code (error={:?})", e),
+    }
+
+    // Remove elements from the front, the middle and the back.
+    for id in [0, 3, NUM_THREADS - 1] {
+        if let Err(e) = unsafe { list.remove(NonNull::from(&threads[id])) } {
+            panic!("failed to remove element (error={:?})", e);
+        }
+        if threads[id].link.is_linked() {
+            panic!("removed element is still linked (id={})", id);
+        }
+    }
+    match unsafe { list.remove(NonNull::from(&threads[3])) } {
+        Ok(_) => panic!("removed element that is not linked"),
+        Err(e) if e.code == ErrorCode::InvalidArgument => {},
+        Err(e) => panic!("unexpected error code (error={:?})", e),
+    }
+
+    // Check if remaining elements are popped in order.
+    if list.len() != NUM_THREADS - 3 {
+        panic!("unexpected length (got={})", list.len());
+    }
+    let mut ids: Vec<usize> = Vec::new();
+    while let Some(thread) = list.pop_front() {
+        ids.push(unsafe { thread.as_ref().id });
+    }
+    if ids != [1, 2, 4, 5, 6] || !list.is_empty() {
+        panic!("unexpected elements (got={:?})", ids);
+    }
+}
+
+/// Attempts to remove and insert elements through a [`ListCursor`].
+#[test]
+fn test_cursor() {
+    let threads: Vec<Thread> = test_helper_create_threads();
+    let mut list: IntrusiveList<RunQueueAdapter> = IntrusiveList::new();
+
+    for thread in threads.iter().skip(1) {
+        if let Err(e) = unsafe { list.push_back(NonNull::from(thread)) } {
+            panic!("failed to push element (error={:?})", e);
+        }
+    }
+
+    // Remove elements with even identifiers, and insert the first one before the element 3.
+    let mut cursor: ListCursor<'_, RunQueueAdapter> = list.cursor_front();
+    while let Some(thread) = cursor.current() {
+        let id: usize = unsafe { thread.as_ref().id };
+        if id % 2 == 0 {
+            cursor.remove_current();
+            continue;
+        }
+        if id == 3 {
+            if let Err(e) = unsafe { cursor.insert_before(NonNull::from(&threads[0])) } {
+                panic!("failed to insert element (error={:?})", e);
+            }
+        }
+        cursor.move_next();
+    }
+
+    let ids: Vec<usize> = test_helper_collect_ids(&mut list);
+    if ids != [1, 0, 3, 5, 7] {
+        panic!("unexpected elements (got={:?})", ids);
+    }
+
+    // Check if elements are unlinked when the list is dropped.
+    drop(list);
+    if threads.iter().any(|thread| thread.link.is_linked()) {
+        panic!("elements are still linked after the list was dropped");
+    }
+}
@@ -5,14 +5,32 @@
 // Modules
 //==================================================================================================
 
+mod atomic_bitmap;
 mod bitmap;
+mod buddy_allocator;
+mod fixed_hash_map;
+mod hierarchical_bitmap;
+mod id_allocator;
+mod intrusive_list;
+mod pool;
 mod raw_array;
+mod ring_buffer;
 mod slab;
+mod slab_allocator;
 
 //==================================================================================================
 // Exports
 //==================================================================================================
 
+pub use atomic_bitmap::*;
 pub use bitmap::*;
+pub use buddy_allocator::*;
+pub use fixed_hash_map::*;
+pub use hierarchical_bitmap::*;
+pub use id_allocator::*;
+pub use intrusive_list::*;
+pub use pool::*;
 pub use raw_array::*;
+pub use ring_buffer::*;
 pub use slab::*;
+pub use slab_allocator::*;
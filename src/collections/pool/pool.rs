@@ -0,0 +1,209 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::{
+    collections::slab::Slab,
+    error::{
+        Error,
+        ErrorCode,
+    },
+};
+use ::core::{
+    cell::RefCell,
+    marker::PhantomData,
+    mem,
+    ops::{
+        Deref,
+        DerefMut,
+    },
+    ptr::{
+        self,
+        NonNull,
+    },
+};
+
+//==================================================================================================
+// Structures
+//==================================================================================================
+
+///
+/// # Description
+///
+/// A pool of objects of type `T` that is backed by a [`Slab`].
+///
+/// Objects are handed out as [`PoolBox`] handles, which return their block to the pool when they
+/// are dropped.
+///
+#[derive(Debug)]
+pub struct Pool<T> {
+    /// Slab allocator that holds objects.
+    slab: RefCell<Slab>,
+    /// Marker for the type of objects.
+    _marker: PhantomData<T>,
+}
+
+///
+/// # Description
+///
+/// An owning handle to an object that lives in a [`Pool`].
+///
+#[derive(Debug)]
+pub struct PoolBox<'a, T> {
+    /// Pool that the object was allocated from.
+    pool: &'a Pool<T>,
+    /// Pointer to the object.
+    ptr: NonNull<T>,
+}
+
+//==================================================================================================
+// Implementations
+//==================================================================================================
+
+impl<T> Pool<T> {
+    ///
+    /// # Description
+    ///
+    /// Creates a new pool of objects on top of a slab allocator.
+    ///
+    /// # Parameters
+    ///
+    /// - `slab`: Slab allocator that holds objects.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, a new pool is returned. Upon failure, an error is returned instead.
+    ///
+    pub fn new(slab: Slab) -> Result<Self, Error> {
+        // Check if objects fit in blocks.
+        if mem::size_of::<T>() > slab.block_size() {
+            return Err(Error::new(ErrorCode::InvalidArgument, "object does not fit in block"));
+        }
+
+        // Check if blocks are suitably aligned to hold objects.
+        if slab.block_alignment() % mem::align_of::<T>() != 0 {
+            return Err(Error::new(ErrorCode::InvalidArgument, "unaligned blocks"));
+        }
+
+        Ok(Self {
+            slab: RefCell::new(slab),
+            _marker: PhantomData,
+        })
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the maximum number of objects that the pool can hold.
+    ///
+    /// # Returns
+    ///
+    /// The maximum number of objects that the pool can hold.
+    ///
+    pub fn capacity(&self) -> usize {
+        self.slab.borrow().capacity()
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the number of objects that are currently allocated from the pool.
+    ///
+    /// # Returns
+    ///
+    /// The number of objects that are currently allocated from the pool.
+    ///
+    pub fn len(&self) -> usize {
+        self.slab.borrow().used_blocks()
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Checks if no objects are currently allocated from the pool.
+    ///
+    /// # Returns
+    ///
+    /// `true` if no objects are allocated from the pool, `false` otherwise.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Allocates an object from the pool and moves `value` into it.
+    ///
+    /// # Parameters
+    ///
+    /// - `value`: Initial value of the object.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, a handle to the object is returned. Upon failure, `value` is returned back
+    /// along with an error, so that the caller may retry.
+    ///
+    pub fn allocate(&self, value: T) -> Result<PoolBox<'_, T>, (T, Error)> {
+        let ptr: *mut T = match self.slab.borrow_mut().allocate() {
+            Ok(ptr) => ptr as *mut T,
+            Err(e) => return Err((value, e)),
+        };
+
+        // Safety: the block is valid, suitably aligned and large enough to hold an object.
+        unsafe { ptr::write(ptr, value) };
+
+        Ok(PoolBox {
+            pool: self,
+            // Safety: blocks handed out by the slab allocator are never null.
+            ptr: unsafe { NonNull::new_unchecked(ptr) },
+        })
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the slab allocator that backs the pool.
+    ///
+    /// # Returns
+    ///
+    /// The slab allocator that backs the pool.
+    ///
+    pub fn into_inner(self) -> Slab {
+        self.slab.into_inner()
+    }
+}
+
+impl<T> Deref for PoolBox<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        // Safety: the object was initialized when it was allocated and it is owned by this handle.
+        unsafe { self.ptr.as_ref() }
+    }
+}
+
+impl<T> DerefMut for PoolBox<'_, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        // Safety: the object was initialized when it was allocated and it is owned by this handle.
+        unsafe { self.ptr.as_mut() }
+    }
+}
+
+impl<T> Drop for PoolBox<'_, T> {
+    fn drop(&mut self) {
+        // Safety: the object was initialized when it was allocated and it is owned by this handle.
+        unsafe { ptr::drop_in_place(self.ptr.as_ptr()) };
+
+        // Return the block to the pool. Errors cannot be reported at this point, and blocks that
+        // fail to be released are never handed out again.
+        let _ = self
+            .pool
+            .slab
+            .borrow_mut()
+            .deallocate(self.ptr.as_ptr() as *const u8);
+    }
+}
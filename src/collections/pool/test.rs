@@ -0,0 +1,198 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::{
+    collections::{
+        Pool,
+        PoolBox,
+        Slab,
+    },
+    error::ErrorCode,
+};
+use ::alloc::{
+    rc::Rc,
+    vec::Vec,
+};
+
+//==================================================================================================
+// Unit Tests
+//==================================================================================================
+
+/// Size of memory regions used in tests.
+const REGION_SIZE: usize = 4096;
+
+/// A memory region that is suitably aligned for slab allocators used in tests.
+#[repr(C, align(4096))]
+struct Region([u8; REGION_SIZE]);
+
+/// An object that is stored in pools used in tests.
+struct Object {
+    /// Identifier of the object.
+    id: usize,
+    /// Counter that tracks how many objects are alive.
+    _alive: Rc<()>,
+}
+
+/// Helper test function that creates a [`Slab`] on a memory region.
+fn test_helper_create_slab(region: &mut Region, block_size: usize) -> Slab {
+    match unsafe { Slab::from_raw_parts(region.0.as_mut_ptr(), REGION_SIZE, block_size) } {
+        Ok(slab) => slab,
+        Err(e) => panic!("failed to create slab (error={:?})", e),
+    }
+}
+
+/// Attempts to allocate objects from a [`Pool`] and access them.
+#[test]
+fn test_allocate() {
+    let mut region: Region = Region([0; REGION_SIZE]);
+    let slab: Slab = test_helper_create_slab(&mut region, 32);
+    let pool: Pool<Object> = match Pool::new(slab) {
+        Ok(pool) => pool,
+        Err(e) => panic!("failed to create pool (error={:?})", e),
+    };
+    let alive: Rc<()> = Rc::new(());
+
+    // Allocate all objects.
+    let mut objects: Vec<PoolBox<'_, Object>> = Vec::new();
+    for id in 0..pool.capacity() {
+        match pool.allocate(Object {
+            id,
+            _alive: alive.clone(),
+        }) {
+            Ok(object) => objects.push(object),
+            Err((_, e)) => panic!("failed to allocate object (error={:?})", e),
+        }
+    }
+
+    // Check if the pool is exhausted.
+    if pool
+        .allocate(Object {
+            id: 0,
+            _alive: alive.clone(),
+        })
+        .is_ok()
+    {
+        panic!("allocated object from an exhausted pool");
+    }
+
+    // Modify objects and check if they hold the expected values.
+    for object in objects.iter_mut() {
+        object.id *= 2;
+    }
+    for (id, object) in objects.iter().enumerate() {
+        if object.id != 2 * id {
+            panic!("unexpected object (expected={}, got={})", 2 * id, object.id);
+        }
+    }
+    if pool.len() != pool.capacity() || Rc::strong_count(&alive) != pool.capacity() + 1 {
+        panic!("unexpected number of objects (got={})", pool.len());
+    }
+
+    // Drop all objects and check if blocks are returned and destructors are run.
+    objects.clear();
+    if !pool.is_empty() || Rc::strong_count(&alive) != 1 {
+        panic!("objects were not released (got={})", pool.len());
+    }
+}
+
+/// Attempts to allocate an object from an exhausted [`Pool`] and get it back.
+#[test]
+fn test_allocate_exhausted() {
+    let mut region: Region = Region([0; REGION_SIZE]);
+    let slab: Slab = test_helper_create_slab(&mut region, 32);
+    let pool: Pool<Object> = match Pool::new(slab) {
+        Ok(pool) => pool,
+        Err(e) => panic!("failed to create pool (error={:?})", e),
+    };
+    let alive: Rc<()> = Rc::new(());
+
+    // Exhaust the pool.
+    let mut objects: Vec<PoolBox<'_, Object>> = Vec::new();
+    for id in 0..pool.capacity() {
+        match pool.allocate(Object {
+            id,
+            _alive: alive.clone(),
+        }) {
+            Ok(object) => objects.push(object),
+            Err((_, e)) => panic!("failed to allocate object (error={:?})", e),
+        }
+    }
+
+    // Check if the object is handed back rather than dropped.
+    let object: Object = match pool.allocate(Object {
+        id: usize::MAX,
+        _alive: alive.clone(),
+    }) {
+        Ok(_) => panic!("allocated object from an exhausted pool"),
+        Err((object, e)) if e.code == ErrorCode::OutOfMemory => object,
+        Err((_, e)) => panic!("unexpected error code (error={:?})", e),
+    };
+    if object.id != usize::MAX || Rc::strong_count(&alive) != pool.capacity() + 2 {
+        panic!("object was not handed back (id={})", object.id);
+    }
+
+    // Retry once a block is released.
+    objects.pop();
+    let object: PoolBox<'_, Object> = match pool.allocate(object) {
+        Ok(object) => object,
+        Err((_, e)) => panic!("failed to allocate object (error={:?})", e),
+    };
+    if object.id != usize::MAX {
+        panic!("unexpected object (id={})", object.id);
+    }
+}
+
+/// Attempts to create a [`Pool`] whose objects do not fit in blocks.
+#[test]
+fn test_object_too_large() {
+    let mut region: Region = Region([0; REGION_SIZE]);
+    let slab: Slab = test_helper_create_slab(&mut region, 8);
+
+    match Pool::<[u64; 2]>::new(slab) {
+        Ok(_) => panic!("created pool with blocks that are too small"),
+        Err(e) if e.code == ErrorCode::InvalidArgument => {},
+        Err(e) => panic!("unexpected error code (error={:?})", e),
+    }
+}
+
+/// Attempts to create a [`Pool`] whose objects are more strictly aligned than blocks.
+#[test]
+fn test_object_misaligned() {
+    /// An object that requires a large alignment.
+    #[repr(align(64))]
+    struct Aligned;
+
+    let mut region: Region = Region([0; REGION_SIZE]);
+    let slab: Slab = test_helper_create_slab(&mut region, 80);
+
+    match Pool::<Aligned>::new(slab) {
+        Ok(_) => panic!("created pool with blocks that are misaligned"),
+        Err(e) if e.code == ErrorCode::InvalidArgument => {},
+        Err(e) => panic!("unexpected error code (error={:?})", e),
+    }
+}
+
+/// Attempts to create a [`Pool`] whose objects are more strictly aligned than blocks whose size was
+/// rounded up to a power of two.
+#[test]
+fn test_object_misaligned_rounded_block() {
+    /// An object that requires a larger alignment than blocks of its size.
+    #[repr(align(32))]
+    struct Aligned {
+        /// Contents of the object.
+        _data: [u8; 24],
+    }
+
+    let mut region: Region = Region([0; REGION_SIZE]);
+    let slab: Slab = test_helper_create_slab(&mut region, 24);
+
+    match Pool::<Aligned>::new(slab) {
+        Ok(_) => panic!("created pool with blocks that are misaligned"),
+        Err(e) if e.code == ErrorCode::InvalidArgument => {},
+        Err(e) => panic!("unexpected error code (error={:?})", e),
+    }
+}
@@ -7,6 +7,7 @@
 //==================================================================================================
 
 mod raw_array;
+mod zeroable;
 
 #[cfg(test)]
 mod test;
@@ -16,3 +17,4 @@ mod test;
 //==================================================================================================
 
 pub use raw_array::*;
+pub use zeroable::*;
@@ -5,13 +5,17 @@
 // Imports
 //==================================================================================================
 
-use crate::error::{
-    Error,
-    ErrorCode,
+use crate::{
+    collections::raw_array::Zeroable,
+    error::{
+        Error,
+        ErrorCode,
+    },
 };
 use ::alloc::alloc;
 use ::core::{
     alloc::Layout,
+    mem,
     ops::{
         Deref,
         DerefMut,
@@ -32,12 +36,16 @@ use ::core::{
 #[derive(Debug)]
 enum RawArrayStorage<T> {
     /// A storage area that is managed by [alloc::GlobalAlloc].
-    Managed { ptr: ptr::NonNull<T>, len: usize },
+    Managed {
+        ptr: ptr::NonNull<T>,
+        len: usize,
+        capacity: usize,
+    },
     /// A storage area that is not managed by [alloc::GlobalAlloc].
     Unmanaged { ptr: ptr::NonNull<T>, len: usize },
 }
 
-impl<T> RawArrayStorage<T> {
+impl<T: Zeroable> RawArrayStorage<T> {
     ///
     /// # Description
     ///
@@ -53,31 +61,17 @@ impl<T> RawArrayStorage<T> {
     /// On failure, an error is returned instead.
     ///
     fn new_managed(len: usize) -> Result<RawArrayStorage<T>, Error> {
-        // Check if the length is invalid.
-        if len == 0 || len >= i32::MAX as usize {
-            return Err(Error::new(ErrorCode::InvalidArgument, "invalid length"));
-        }
-
-        // Allocate underlying memory.
-        let layout: Layout = match Layout::array::<T>(len) {
-            Ok(layout) => layout,
-            Err(_) => return Err(Error::new(ErrorCode::InvalidArgument, "invalid layout")),
-        };
-        let ptr: ptr::NonNull<T> = {
-            let ptr: *mut u8 = unsafe { alloc::alloc(layout) };
-            match ptr::NonNull::new(ptr as *mut T) {
-                Some(p) => p,
-                None => {
-                    return Err(Error::new(ErrorCode::OutOfMemory, "out of memory"));
-                },
-            }
-        };
+        let ptr: ptr::NonNull<T> = Self::allocate(len)?;
 
         // Initialize the backing storage.
         // Safety: The memory region is valid and the length is valid.
         unsafe { ptr::write_bytes(ptr.as_ptr(), 0, len) };
 
-        Ok(RawArrayStorage::Managed { ptr, len })
+        Ok(RawArrayStorage::Managed {
+            ptr,
+            len,
+            capacity: len,
+        })
     }
 
     ///
@@ -99,9 +93,12 @@ impl<T> RawArrayStorage<T> {
     ///
     /// Behavior is undefined if any of the following conditions are violated:
     ///
-    /// - `ptr` must be valid for both reads and writes for `len * mem::size_of::<T>()` many bytes.
+    /// - `ptr` must be valid for both reads and writes of `len` elements of type `T`.
     /// - `ptr` must be properly aligned.
-    /// - `ptr` must point to len consecutive properly initialized values of type `T``.
+    /// - The memory must not be accessed through any other pointer while it is in use.
+    ///
+    /// The memory need not be initialized, as it is zero-filled, and zero bytes are a valid `T`
+    /// because `T` is [`Zeroable`]. Any previous contents are overwritten without being dropped.
     ///
     unsafe fn new_unmanaged(ptr: *mut T, len: usize) -> Result<RawArrayStorage<T>, Error> {
         // Check if the length is invalid.
@@ -125,6 +122,205 @@ impl<T> RawArrayStorage<T> {
 
         Ok(RawArrayStorage::Unmanaged { ptr, len })
     }
+}
+
+impl<T> RawArrayStorage<T> {
+    ///
+    /// # Description
+    ///
+    /// Constructs backing storage for a raw array, initializing each element with a closure.
+    ///
+    /// # Parameters
+    ///
+    /// - `len`: Length of the backing storage.
+    /// - `f`: Closure that returns the initial value of the element at a given index.
+    ///
+    /// # Returns
+    ///
+    /// On success, the backing storage is returned. On failure, an error is returned instead.
+    ///
+    fn new_managed_with<F: FnMut(usize) -> T>(
+        len: usize,
+        mut f: F,
+    ) -> Result<RawArrayStorage<T>, Error> {
+        let ptr: ptr::NonNull<T> = Self::allocate(len)?;
+
+        // Initialize the backing storage.
+        for i in 0..len {
+            // Safety: The element lies within the memory region that was just allocated.
+            unsafe { ptr.as_ptr().add(i).write(f(i)) };
+        }
+
+        Ok(RawArrayStorage::Managed {
+            ptr,
+            len,
+            capacity: len,
+        })
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Allocates uninitialized memory for a managed backing storage.
+    ///
+    /// # Parameters
+    ///
+    /// - `capacity`: Number of elements that the memory should hold.
+    ///
+    /// # Returns
+    ///
+    /// On success, a pointer to the allocated memory is returned. On failure, an error is
+    /// returned instead.
+    ///
+    fn allocate(capacity: usize) -> Result<ptr::NonNull<T>, Error> {
+        // Check if the length is invalid.
+        if capacity == 0 || capacity >= i32::MAX as usize {
+            return Err(Error::new(ErrorCode::InvalidArgument, "invalid length"));
+        }
+
+        // Zero-sized types do not need any memory.
+        if mem::size_of::<T>() == 0 {
+            return Ok(ptr::NonNull::dangling());
+        }
+
+        // Allocate underlying memory.
+        let layout: Layout = Self::layout(capacity)?;
+        let ptr: *mut u8 = unsafe { alloc::alloc(layout) };
+        match ptr::NonNull::new(ptr as *mut T) {
+            Some(p) => Ok(p),
+            None => Err(Error::new(ErrorCode::OutOfMemory, "out of memory")),
+        }
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Computes the memory layout of a managed backing storage.
+    ///
+    /// # Parameters
+    ///
+    /// - `capacity`: Number of elements that the backing storage holds.
+    ///
+    /// # Returns
+    ///
+    /// On success, the memory layout is returned. On failure, an error is returned instead.
+    ///
+    fn layout(capacity: usize) -> Result<Layout, Error> {
+        match Layout::array::<T>(capacity) {
+            Ok(layout) => Ok(layout),
+            Err(_) => Err(Error::new(ErrorCode::InvalidArgument, "invalid layout")),
+        }
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Ensures that a managed backing storage can hold at least `additional` more elements
+    /// without reallocating.
+    ///
+    /// # Parameters
+    ///
+    /// - `additional`: Number of additional elements.
+    ///
+    /// # Returns
+    ///
+    /// On success, empty is returned. On failure, an error is returned instead and the backing
+    /// storage is left unchanged.
+    ///
+    fn try_reserve(&mut self, additional: usize) -> Result<(), Error> {
+        let (ptr, len, capacity) = match self {
+            RawArrayStorage::Managed { ptr, len, capacity } => (ptr, *len, capacity),
+            RawArrayStorage::Unmanaged { .. } => {
+                return Err(Error::new(ErrorCode::OperationNotSupported, "unmanaged storage"));
+            },
+        };
+
+        // Check if the requested capacity is invalid.
+        let required: usize = match len.checked_add(additional) {
+            Some(required) if required < i32::MAX as usize => required,
+            _ => return Err(Error::new(ErrorCode::InvalidArgument, "invalid length")),
+        };
+
+        // Check if the backing storage is already large enough.
+        if required <= *capacity {
+            return Ok(());
+        }
+
+        // Grow underlying memory. Zero-sized types do not need any memory.
+        if mem::size_of::<T>() != 0 {
+            let old_layout: Layout = Self::layout(*capacity)?;
+            let new_layout: Layout = Self::layout(required)?;
+            // Safety: The memory was allocated with the old layout, and the new size is non-zero.
+            let new_ptr: *mut u8 =
+                unsafe { alloc::realloc(ptr.as_ptr() as *mut u8, old_layout, new_layout.size()) };
+            *ptr = match ptr::NonNull::new(new_ptr as *mut T) {
+                Some(p) => p,
+                None => return Err(Error::new(ErrorCode::OutOfMemory, "out of memory")),
+            };
+        }
+        *capacity = required;
+
+        Ok(())
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Resizes a managed backing storage. If it grows, new elements are clones of `value`. If it
+    /// shrinks, elements past the new length are dropped.
+    ///
+    /// # Parameters
+    ///
+    /// - `new_len`: New length of the backing storage.
+    /// - `value`: Value of new elements.
+    ///
+    /// # Returns
+    ///
+    /// On success, empty is returned. On failure, an error is returned instead and the backing
+    /// storage is left unchanged.
+    ///
+    fn resize(&mut self, new_len: usize, value: T) -> Result<(), Error>
+    where
+        T: Clone,
+    {
+        // Check if the length is invalid.
+        if new_len == 0 {
+            return Err(Error::new(ErrorCode::InvalidArgument, "invalid length"));
+        }
+
+        // Make room for new elements.
+        let old_len: usize = self.get().len();
+        if new_len > old_len {
+            self.try_reserve(new_len - old_len)?;
+        }
+
+        let (ptr, len) = match self {
+            RawArrayStorage::Managed { ptr, len, .. } => (ptr.as_ptr(), len),
+            RawArrayStorage::Unmanaged { .. } => {
+                return Err(Error::new(ErrorCode::OperationNotSupported, "unmanaged storage"));
+            },
+        };
+
+        if new_len < old_len {
+            *len = new_len;
+            // Safety: The dropped elements are initialized and are no longer reachable.
+            unsafe {
+                ptr::drop_in_place(ptr::slice_from_raw_parts_mut(
+                    ptr.add(new_len),
+                    old_len - new_len,
+                ))
+            };
+        } else {
+            // Safety: The new elements lie within the reserved memory. The length is updated as
+            // they are written, so that only initialized elements are ever reachable.
+            for i in old_len..new_len {
+                unsafe { ptr.add(i).write(value.clone()) };
+                *len = i + 1;
+            }
+        }
+
+        Ok(())
+    }
 
     ///
     /// # Description
@@ -137,7 +333,7 @@ impl<T> RawArrayStorage<T> {
     ///
     fn get_mut(&mut self) -> &mut [T] {
         match self {
-            RawArrayStorage::Managed { ptr, len } => unsafe {
+            RawArrayStorage::Managed { ptr, len, .. } => unsafe {
                 slice::from_raw_parts_mut(ptr.as_ptr(), *len)
             },
             RawArrayStorage::Unmanaged { ptr, len } => unsafe {
@@ -157,7 +353,7 @@ impl<T> RawArrayStorage<T> {
     ///
     fn get(&self) -> &[T] {
         match self {
-            RawArrayStorage::Managed { ptr, len } => unsafe {
+            RawArrayStorage::Managed { ptr, len, .. } => unsafe {
                 slice::from_raw_parts(ptr.as_ptr(), *len)
             },
             RawArrayStorage::Unmanaged { ptr, len } => unsafe {
@@ -174,7 +370,11 @@ impl<T> RawArrayStorage<T> {
 ///
 /// # Description
 ///
-/// A type that represent a fixed-size array.
+/// A type that represent an array.
+///
+/// Arrays are either managed, in which case their memory is allocated from [alloc::GlobalAlloc]
+/// and may be resized, or unmanaged, in which case they live in a fixed memory region that is
+/// provided by the caller. Elements of managed arrays are dropped along with the array.
 ///
 #[derive(Debug)]
 pub struct RawArray<T> {
@@ -182,7 +382,7 @@ pub struct RawArray<T> {
     storage: RawArrayStorage<T>,
 }
 
-impl<T> RawArray<T> {
+impl<T: Zeroable> RawArray<T> {
     ///
     /// # Description
     ///
@@ -197,6 +397,11 @@ impl<T> RawArray<T> {
     /// On success, the new managed array is returned, with all bits set to zero.
     /// On failure, an error is returned instead.
     ///
+    /// # Notes
+    ///
+    /// Arrays of types that are not [`Zeroable`] should be constructed with
+    /// [`RawArray::new_with()`] or [`RawArray::from_elem()`] instead.
+    ///
     pub fn new(len: usize) -> Result<RawArray<T>, Error> {
         Ok(RawArray {
             storage: RawArrayStorage::new_managed(len)?,
@@ -222,9 +427,12 @@ impl<T> RawArray<T> {
     ///
     /// Behavior is undefined if any of the following conditions are violated:
     ///
-    /// - `ptr` must be valid for both reads and writes for `len * mem::size_of::<T>()` many bytes.
+    /// - `ptr` must be valid for both reads and writes of `len` elements of type `T`.
     /// - `ptr` must be properly aligned.
-    /// - `ptr` must point to len consecutive properly initialized values of type `T``.
+    /// - The memory must not be accessed through any other pointer while it is in use.
+    ///
+    /// The memory need not be initialized, as it is zero-filled, and zero bytes are a valid `T`
+    /// because `T` is [`Zeroable`]. Any previous contents are overwritten without being dropped.
     ///
     pub unsafe fn from_raw_parts(ptr: *mut T, len: usize) -> Result<RawArray<T>, Error> {
         Ok(RawArray {
@@ -233,6 +441,107 @@ impl<T> RawArray<T> {
     }
 }
 
+impl<T> RawArray<T> {
+    ///
+    /// # Description
+    ///
+    /// Constructs a new managed array, initializing each element with a closure.
+    ///
+    /// # Parameters
+    ///
+    /// - `len`: Length of the array.
+    /// - `f`: Closure that returns the initial value of the element at a given index.
+    ///
+    /// # Returns
+    ///
+    /// On success, the new managed array is returned. On failure, an error is returned instead.
+    ///
+    pub fn new_with<F: FnMut(usize) -> T>(len: usize, f: F) -> Result<RawArray<T>, Error> {
+        Ok(RawArray {
+            storage: RawArrayStorage::new_managed_with(len, f)?,
+        })
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Constructs a new managed array with all elements set to clones of `elem`.
+    ///
+    /// # Parameters
+    ///
+    /// - `elem`: Initial value of elements.
+    /// - `len`: Length of the array.
+    ///
+    /// # Returns
+    ///
+    /// On success, the new managed array is returned. On failure, an error is returned instead.
+    ///
+    pub fn from_elem(elem: T, len: usize) -> Result<RawArray<T>, Error>
+    where
+        T: Clone,
+    {
+        Self::new_with(len, |_| elem.clone())
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the number of elements that the array can hold without reallocating.
+    ///
+    /// # Returns
+    ///
+    /// The number of elements that the array can hold without reallocating.
+    ///
+    pub fn capacity(&self) -> usize {
+        match &self.storage {
+            RawArrayStorage::Managed { capacity, .. } => *capacity,
+            RawArrayStorage::Unmanaged { len, .. } => *len,
+        }
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Ensures that a managed array can grow by at least `additional` elements without
+    /// reallocating.
+    ///
+    /// # Parameters
+    ///
+    /// - `additional`: Number of additional elements.
+    ///
+    /// # Returns
+    ///
+    /// On success, empty is returned. On failure, an error is returned instead and the array is
+    /// left unchanged.
+    ///
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), Error> {
+        self.storage.try_reserve(additional)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Resizes a managed array. If it grows, new elements are clones of `value`. If it shrinks,
+    /// elements past the new length are dropped.
+    ///
+    /// # Parameters
+    ///
+    /// - `new_len`: New length of the array.
+    /// - `value`: Value of new elements.
+    ///
+    /// # Returns
+    ///
+    /// On success, empty is returned. On failure, an error is returned instead and the array is
+    /// left unchanged.
+    ///
+    pub fn resize(&mut self, new_len: usize, value: T) -> Result<(), Error>
+    where
+        T: Clone,
+    {
+        self.storage.resize(new_len, value)
+    }
+}
+
 impl<T> Deref for RawArray<T> {
     type Target = [T];
 
@@ -250,8 +559,17 @@ impl<T> DerefMut for RawArray<T> {
 impl<T> Drop for RawArray<T> {
     fn drop(&mut self) {
         match &self.storage {
-            RawArrayStorage::Managed { ptr, len } => {
-                let layout: Layout = match Layout::array::<T>(*len) {
+            RawArrayStorage::Managed { ptr, len, capacity } => {
+                // Drop elements.
+                // Safety: All elements are initialized and are no longer reachable.
+                unsafe { ptr::drop_in_place(ptr::slice_from_raw_parts_mut(ptr.as_ptr(), *len)) };
+
+                // Zero-sized types do not use any memory.
+                if mem::size_of::<T>() == 0 {
+                    return;
+                }
+
+                let layout: Layout = match Layout::array::<T>(*capacity) {
                     Ok(layout) => layout,
                     Err(_) => return,
                 };
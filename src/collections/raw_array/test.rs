@@ -9,7 +9,11 @@ use crate::{
     collections::raw_array::RawArray,
     error::ErrorCode,
 };
-use ::core::ptr;
+use ::alloc::rc::Rc;
+use ::core::ptr::{
+    self,
+    NonNull,
+};
 
 //==================================================================================================
 // Unit Tests
@@ -75,3 +79,79 @@ fn test_from_raw_parts_wrapping_memory_region() {
         Err(e) => panic!("unexpected error code (error={:?})", e),
     }
 }
+
+/// Attempts to create a [`RawArray`] of a type that cannot be zero-initialized.
+#[test]
+fn test_new_with() {
+    let mut data: [u8; 4] = [0, 1, 2, 3];
+    let base: *mut u8 = data.as_mut_ptr();
+    let array: RawArray<NonNull<u8>> =
+        match RawArray::new_with(data.len(), |i| unsafe { NonNull::new_unchecked(base.add(i)) }) {
+            Ok(array) => array,
+            Err(e) => panic!("failed to create array (error={:?})", e),
+        };
+
+    // Check if elements were initialized by the closure.
+    for (i, ptr) in array.iter().enumerate() {
+        if unsafe { *ptr.as_ptr() } != i as u8 {
+            panic!("element was not initialized (index={})", i);
+        }
+    }
+}
+
+/// Attempts to resize a [`RawArray`] and check that elements are cloned and dropped.
+#[test]
+fn test_resize() {
+    let elem: Rc<()> = Rc::new(());
+    let mut array: RawArray<Rc<()>> = match RawArray::from_elem(elem.clone(), 4) {
+        Ok(array) => array,
+        Err(e) => panic!("failed to create array (error={:?})", e),
+    };
+    if array.len() != 4 || Rc::strong_count(&elem) != 5 {
+        panic!("unexpected array (len={}, count={})", array.len(), Rc::strong_count(&elem));
+    }
+
+    // Grow the array.
+    if let Err(e) = array.try_reserve(12) {
+        panic!("failed to reserve memory (error={:?})", e);
+    }
+    if array.capacity() < 16 || array.len() != 4 {
+        panic!("unexpected array (len={}, capacity={})", array.len(), array.capacity());
+    }
+    if let Err(e) = array.resize(16, elem.clone()) {
+        panic!("failed to grow array (error={:?})", e);
+    }
+    if array.len() != 16 || Rc::strong_count(&elem) != 17 {
+        panic!("unexpected array (len={}, count={})", array.len(), Rc::strong_count(&elem));
+    }
+
+    // Shrink the array.
+    if let Err(e) = array.resize(2, elem.clone()) {
+        panic!("failed to shrink array (error={:?})", e);
+    }
+    if array.len() != 2 || Rc::strong_count(&elem) != 3 {
+        panic!("unexpected array (len={}, count={})", array.len(), Rc::strong_count(&elem));
+    }
+
+    // Drop the array.
+    drop(array);
+    if Rc::strong_count(&elem) != 1 {
+        panic!("elements were not dropped (count={})", Rc::strong_count(&elem));
+    }
+}
+
+/// Attempts to resize an unmanaged [`RawArray`].
+#[test]
+fn test_resize_unmanaged() {
+    let mut data: [u8; 4] = [1; 4];
+    let mut array: RawArray<u8> =
+        match unsafe { RawArray::from_raw_parts(data.as_mut_ptr(), data.len()) } {
+            Ok(array) => array,
+            Err(e) => panic!("failed to create array from raw parts (error={:?})", e),
+        };
+    match array.resize(8, 0) {
+        Ok(_) => panic!("resized unmanaged array"),
+        Err(e) if e.code == ErrorCode::OperationNotSupported => {},
+        Err(e) => panic!("unexpected error code (error={:?})", e),
+    }
+}
@@ -0,0 +1,84 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use ::core::{
+    cell::UnsafeCell,
+    mem::MaybeUninit,
+    sync::atomic::{
+        AtomicBool,
+        AtomicI16,
+        AtomicI32,
+        AtomicI8,
+        AtomicIsize,
+        AtomicU16,
+        AtomicU32,
+        AtomicU8,
+        AtomicUsize,
+    },
+};
+
+//==================================================================================================
+// Traits
+//==================================================================================================
+
+///
+/// # Description
+///
+/// A marker for types whose values may have all bits set to zero.
+///
+/// Zero-filled values of these types are valid, and dropping them has no effect. This is what
+/// allows [`RawArray::new()`](crate::collections::RawArray::new) and
+/// [`RawArray::from_raw_parts()`](crate::collections::RawArray::from_raw_parts) to zero-fill
+/// elements.
+///
+/// # Safety
+///
+/// Implementations must ensure that a value with all bits set to zero is a valid value of the
+/// type, and that such a value owns no resources.
+///
+pub unsafe trait Zeroable {}
+
+//==================================================================================================
+// Implementations
+//==================================================================================================
+
+///
+/// # Description
+///
+/// Implements [`Zeroable`] for a list of types.
+///
+/// # Parameters
+///
+/// - `$t:ty`: Types to implement [`Zeroable`] for.
+///
+macro_rules! impl_zeroable {
+    ($($t:ty),*) => {
+        $(unsafe impl Zeroable for $t {})*
+    };
+}
+
+impl_zeroable!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, bool);
+impl_zeroable!(
+    AtomicBool,
+    AtomicU8,
+    AtomicU16,
+    AtomicU32,
+    AtomicUsize,
+    AtomicI8,
+    AtomicI16,
+    AtomicI32,
+    AtomicIsize
+);
+
+#[cfg(target_has_atomic = "64")]
+impl_zeroable!(core::sync::atomic::AtomicU64, core::sync::atomic::AtomicI64);
+
+unsafe impl<T> Zeroable for MaybeUninit<T> {}
+
+unsafe impl<T: Zeroable> Zeroable for UnsafeCell<T> {}
+
+unsafe impl<T: Zeroable, const N: usize> Zeroable for [T; N] {}
@@ -0,0 +1,209 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::{
+    collections::raw_array::RawArray,
+    error::{
+        Error,
+        ErrorCode,
+    },
+};
+use ::core::mem::MaybeUninit;
+
+//==================================================================================================
+// Structures
+//==================================================================================================
+
+///
+/// # Description
+///
+/// A first-in first-out queue with fixed capacity.
+///
+#[derive(Debug)]
+pub struct RingBuffer<T> {
+    /// Slots that hold elements.
+    slots: RawArray<MaybeUninit<T>>,
+    /// Index of the slot that holds the oldest element.
+    head: usize,
+    /// Number of elements in the ring buffer.
+    len: usize,
+}
+
+//==================================================================================================
+// Implementations
+//==================================================================================================
+
+impl<T> RingBuffer<T> {
+    ///
+    /// # Description
+    ///
+    /// Creates a new ring buffer.
+    ///
+    /// # Parameters
+    ///
+    /// - `capacity`: Maximum number of elements in the ring buffer.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, a new empty ring buffer is returned. Upon failure, an error is returned
+    /// instead.
+    ///
+    pub fn new(capacity: usize) -> Result<Self, Error> {
+        Ok(Self::from_raw_array(RawArray::new(capacity)?))
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Creates a new ring buffer on top of a raw array. Each slot of the array holds one element.
+    ///
+    /// # Parameters
+    ///
+    /// - `slots`: Raw array that holds elements.
+    ///
+    /// # Returns
+    ///
+    /// A new empty ring buffer.
+    ///
+    pub fn from_raw_array(slots: RawArray<MaybeUninit<T>>) -> Self {
+        Self {
+            slots,
+            head: 0,
+            len: 0,
+        }
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the maximum number of elements in the ring buffer.
+    ///
+    /// # Returns
+    ///
+    /// The maximum number of elements in the ring buffer.
+    ///
+    pub fn capacity(&self) -> usize {
+        self.slots.len()
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the number of elements in the ring buffer.
+    ///
+    /// # Returns
+    ///
+    /// The number of elements in the ring buffer.
+    ///
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Checks if the ring buffer is empty.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the ring buffer holds no elements, `false` otherwise.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Checks if the ring buffer is full.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the ring buffer cannot hold any more elements, `false` otherwise.
+    ///
+    pub fn is_full(&self) -> bool {
+        self.len == self.capacity()
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Appends an element to the back of the ring buffer.
+    ///
+    /// # Parameters
+    ///
+    /// - `value`: Element to append.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, empty is returned. Upon failure, the element is returned back along with an
+    /// error, so that the caller may retry.
+    ///
+    pub fn push(&mut self, value: T) -> Result<(), (T, Error)> {
+        // Check if the ring buffer is full.
+        if self.is_full() {
+            return Err((value, Error::new(ErrorCode::NoBufferSpace, "ring buffer is full")));
+        }
+
+        let tail: usize = (self.head + self.len) % self.capacity();
+        self.slots[tail].write(value);
+        self.len += 1;
+
+        Ok(())
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Removes the element at the front of the ring buffer.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, the oldest element in the ring buffer is returned. Upon failure, an error is
+    /// returned instead.
+    ///
+    pub fn pop(&mut self) -> Result<T, Error> {
+        // Check if the ring buffer is empty.
+        if self.is_empty() {
+            return Err(Error::new(ErrorCode::NoMessageAvailable, "ring buffer is empty"));
+        }
+
+        // Safety: the slot at the head holds an initialized element, which is moved out of it.
+        let value: T = unsafe { self.slots[self.head].assume_init_read() };
+        self.head = (self.head + 1) % self.capacity();
+        self.len -= 1;
+
+        Ok(value)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns a reference to the element at the front of the ring buffer.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, a reference to the oldest element in the ring buffer is returned. Upon
+    /// failure, an error is returned instead.
+    ///
+    pub fn peek(&self) -> Result<&T, Error> {
+        // Check if the ring buffer is empty.
+        if self.is_empty() {
+            return Err(Error::new(ErrorCode::NoMessageAvailable, "ring buffer is empty"));
+        }
+
+        // Safety: the slot at the head holds an initialized element.
+        Ok(unsafe { self.slots[self.head].assume_init_ref() })
+    }
+}
+
+impl<T> Drop for RingBuffer<T> {
+    fn drop(&mut self) {
+        // Drop elements that are left in the ring buffer.
+        while self.pop().is_ok() {}
+    }
+}
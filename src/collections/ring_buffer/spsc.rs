@@ -0,0 +1,383 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::{
+    collections::raw_array::RawArray,
+    error::{
+        Error,
+        ErrorCode,
+    },
+};
+use ::core::{
+    cell::UnsafeCell,
+    mem::MaybeUninit,
+    sync::atomic::{
+        AtomicUsize,
+        Ordering,
+    },
+};
+
+//==================================================================================================
+// Structures
+//==================================================================================================
+
+///
+/// # Description
+///
+/// A lock-free first-in first-out queue with fixed capacity, for a single producer and a single
+/// consumer.
+///
+/// Elements are pushed through a [`SpscProducer`] and popped through a [`SpscConsumer`], which are
+/// obtained with [`SpscRingBuffer::split()`]. Each handle may live in a different context, such as
+/// an interrupt handler and a thread.
+///
+/// Head and tail positions range over twice the capacity, so that a full ring buffer can be told
+/// apart from an empty one without wasting a slot.
+///
+#[derive(Debug)]
+pub struct SpscRingBuffer<T> {
+    /// Slots that hold elements.
+    slots: RawArray<UnsafeCell<MaybeUninit<T>>>,
+    /// Position of the oldest element. It is only written by the consumer.
+    head: AtomicUsize,
+    /// Position of the next free slot. It is only written by the producer.
+    tail: AtomicUsize,
+}
+
+// SAFETY: each slot is accessed either by the producer or by the consumer, as arbitrated by head
+// and tail positions.
+unsafe impl<T: Send> Sync for SpscRingBuffer<T> {}
+
+///
+/// # Description
+///
+/// The producer side of a [`SpscRingBuffer`].
+///
+#[derive(Debug)]
+pub struct SpscProducer<'a, T> {
+    /// Underlying ring buffer.
+    ring: &'a SpscRingBuffer<T>,
+}
+
+///
+/// # Description
+///
+/// The consumer side of a [`SpscRingBuffer`].
+///
+#[derive(Debug)]
+pub struct SpscConsumer<'a, T> {
+    /// Underlying ring buffer.
+    ring: &'a SpscRingBuffer<T>,
+}
+
+//==================================================================================================
+// Implementations
+//==================================================================================================
+
+impl<T> SpscRingBuffer<T> {
+    ///
+    /// # Description
+    ///
+    /// Creates a new ring buffer.
+    ///
+    /// # Parameters
+    ///
+    /// - `capacity`: Maximum number of elements in the ring buffer.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, a new empty ring buffer is returned. Upon failure, an error is returned
+    /// instead.
+    ///
+    pub fn new(capacity: usize) -> Result<Self, Error> {
+        Self::from_raw_array(RawArray::new(capacity)?)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Creates a new ring buffer on top of a raw array. Each slot of the array holds one element.
+    ///
+    /// # Parameters
+    ///
+    /// - `slots`: Raw array that holds elements.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, a new empty ring buffer is returned. Upon failure, an error is returned
+    /// instead.
+    ///
+    pub fn from_raw_array(slots: RawArray<UnsafeCell<MaybeUninit<T>>>) -> Result<Self, Error> {
+        // Check if positions can range over twice the capacity.
+        if slots.len() > usize::MAX / 2 {
+            return Err(Error::new(ErrorCode::InvalidArgument, "invalid capacity"));
+        }
+
+        Ok(Self {
+            slots,
+            head: AtomicUsize::new(0),
+            tail: AtomicUsize::new(0),
+        })
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Splits the ring buffer into its producer and consumer sides.
+    ///
+    /// # Returns
+    ///
+    /// The producer and consumer sides of the ring buffer.
+    ///
+    pub fn split(&mut self) -> (SpscProducer<'_, T>, SpscConsumer<'_, T>) {
+        (SpscProducer { ring: self }, SpscConsumer { ring: self })
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the maximum number of elements in the ring buffer.
+    ///
+    /// # Returns
+    ///
+    /// The maximum number of elements in the ring buffer.
+    ///
+    pub fn capacity(&self) -> usize {
+        self.slots.len()
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the number of elements in the ring buffer. The result may be stale if the ring
+    /// buffer is concurrently accessed.
+    ///
+    /// # Returns
+    ///
+    /// The number of elements in the ring buffer.
+    ///
+    pub fn len(&self) -> usize {
+        let head: usize = self.head.load(Ordering::Acquire);
+        let tail: usize = self.tail.load(Ordering::Acquire);
+        self.distance(head, tail)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Checks if the ring buffer is empty. The result may be stale if the ring buffer is
+    /// concurrently accessed.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the ring buffer holds no elements, `false` otherwise.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Checks if the ring buffer is full. The result may be stale if the ring buffer is
+    /// concurrently accessed.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the ring buffer cannot hold any more elements, `false` otherwise.
+    ///
+    pub fn is_full(&self) -> bool {
+        self.len() == self.capacity()
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Computes the number of elements between two positions.
+    ///
+    /// # Parameters
+    ///
+    /// - `head`: Position of the oldest element.
+    /// - `tail`: Position of the next free slot.
+    ///
+    /// # Returns
+    ///
+    /// The number of elements between the two positions.
+    ///
+    fn distance(&self, head: usize, tail: usize) -> usize {
+        (tail + 2 * self.capacity() - head) % (2 * self.capacity())
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Advances a position by one slot.
+    ///
+    /// # Parameters
+    ///
+    /// - `position`: Position to advance.
+    ///
+    /// # Returns
+    ///
+    /// The next position.
+    ///
+    fn next(&self, position: usize) -> usize {
+        (position + 1) % (2 * self.capacity())
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the slot at a position.
+    ///
+    /// # Parameters
+    ///
+    /// - `position`: Target position.
+    ///
+    /// # Returns
+    ///
+    /// A pointer to the slot at the target position.
+    ///
+    fn slot(&self, position: usize) -> *mut MaybeUninit<T> {
+        self.slots[position % self.capacity()].get()
+    }
+}
+
+impl<T> Drop for SpscRingBuffer<T> {
+    fn drop(&mut self) {
+        // Drop elements that are left in the ring buffer.
+        let (_, mut consumer) = self.split();
+        while consumer.pop().is_ok() {}
+    }
+}
+
+impl<T> SpscProducer<'_, T> {
+    ///
+    /// # Description
+    ///
+    /// Appends an element to the back of the ring buffer.
+    ///
+    /// # Parameters
+    ///
+    /// - `value`: Element to append.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, empty is returned. Upon failure, the element is returned back along with an
+    /// error, so that the caller may retry.
+    ///
+    pub fn push(&mut self, value: T) -> Result<(), (T, Error)> {
+        let tail: usize = self.ring.tail.load(Ordering::Relaxed);
+        let head: usize = self.ring.head.load(Ordering::Acquire);
+
+        // Check if the ring buffer is full.
+        if self.ring.distance(head, tail) == self.ring.capacity() {
+            return Err((value, Error::new(ErrorCode::NoBufferSpace, "ring buffer is full")));
+        }
+
+        // Safety: the slot is free, and the consumer does not access it until the tail advances.
+        unsafe { (*self.ring.slot(tail)).write(value) };
+        self.ring
+            .tail
+            .store(self.ring.next(tail), Ordering::Release);
+
+        Ok(())
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Checks if the ring buffer is full.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the ring buffer cannot hold any more elements, `false` otherwise.
+    ///
+    pub fn is_full(&self) -> bool {
+        self.ring.is_full()
+    }
+}
+
+impl<T> SpscConsumer<'_, T> {
+    ///
+    /// # Description
+    ///
+    /// Removes the element at the front of the ring buffer.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, the oldest element in the ring buffer is returned. Upon failure, an error is
+    /// returned instead.
+    ///
+    pub fn pop(&mut self) -> Result<T, Error> {
+        let head: usize = self.ring.head.load(Ordering::Relaxed);
+        let tail: usize = self.ring.tail.load(Ordering::Acquire);
+
+        // Check if the ring buffer is empty.
+        if head == tail {
+            return Err(Error::new(ErrorCode::NoMessageAvailable, "ring buffer is empty"));
+        }
+
+        // Safety: the slot holds an initialized element, and the producer does not access it until
+        // the head advances.
+        let value: T = unsafe { (*self.ring.slot(head)).assume_init_read() };
+        self.ring
+            .head
+            .store(self.ring.next(head), Ordering::Release);
+
+        Ok(value)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns a reference to the element at the front of the ring buffer.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, a reference to the oldest element in the ring buffer is returned. Upon
+    /// failure, an error is returned instead.
+    ///
+    pub fn peek(&self) -> Result<&T, Error> {
+        let head: usize = self.ring.head.load(Ordering::Relaxed);
+        let tail: usize = self.ring.tail.load(Ordering::Acquire);
+
+        // Check if the ring buffer is empty.
+        if head == tail {
+            return Err(Error::new(ErrorCode::NoMessageAvailable, "ring buffer is empty"));
+        }
+
+        // Safety: the slot holds an initialized element, which is only moved out by this consumer.
+        Ok(unsafe { (*self.ring.slot(head)).assume_init_ref() })
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the number of elements in the ring buffer.
+    ///
+    /// # Returns
+    ///
+    /// The number of elements in the ring buffer.
+    ///
+    pub fn len(&self) -> usize {
+        self.ring.len()
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Checks if the ring buffer is empty.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the ring buffer holds no elements, `false` otherwise.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.ring.is_empty()
+    }
+}
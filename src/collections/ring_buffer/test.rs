@@ -0,0 +1,153 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+extern crate std;
+
+use crate::{
+    collections::{
+        RawArray,
+        RingBuffer,
+        SpscConsumer,
+        SpscProducer,
+        SpscRingBuffer,
+    },
+    error::ErrorCode,
+};
+use ::alloc::rc::Rc;
+use ::core::mem::MaybeUninit;
+use std::thread;
+
+//==================================================================================================
+// Unit Tests
+//==================================================================================================
+
+/// Capacity of ring buffers used in tests.
+const CAPACITY: usize = 4;
+
+/// Number of elements exchanged in stress tests.
+const NUM_ELEMENTS: usize = 100_000;
+
+/// Attempts to push and pop elements to and from a [`RingBuffer`], wrapping around its end.
+#[test]
+fn test_push_pop() {
+    let mut ring: RingBuffer<usize> = match RingBuffer::new(CAPACITY) {
+        Ok(ring) => ring,
+        Err(e) => panic!("failed to create ring buffer (error={:?})", e),
+    };
+
+    // Offset head and tail, so that elements wrap around the end of the ring buffer.
+    if let Err((_, e)) = ring.push(0) {
+        panic!("failed to push element (error={:?})", e);
+    }
+    if let Err(e) = ring.pop() {
+        panic!("failed to pop element (error={:?})", e);
+    }
+
+    for round in 0..3 {
+        // Fill the ring buffer.
+        for i in 0..CAPACITY {
+            if let Err((_, e)) = ring.push(round * CAPACITY + i) {
+                panic!("failed to push element (error={:?})", e);
+            }
+        }
+        if !ring.is_full() || ring.len() != CAPACITY {
+            panic!("ring buffer should be full (len={})", ring.len());
+        }
+        match ring.push(usize::MAX) {
+            Ok(_) => panic!("pushed element to a full ring buffer"),
+            Err((usize::MAX, e)) if e.code == ErrorCode::NoBufferSpace => {},
+            Err((value, e)) => panic!("unexpected error (value={}, error={:?})", value, e),
+        }
+
+        // Drain the ring buffer, in first-in first-out order.
+        for i in 0..CAPACITY {
+            let expected: usize = round * CAPACITY + i;
+            match ring.peek() {
+                Ok(value) if *value == expected => {},
+                Ok(value) => panic!("unexpected element (expected={}, got={})", expected, value),
+                Err(e) => panic!("failed to peek element (error={:?})", e),
+            }
+            match ring.pop() {
+                Ok(value) if value == expected => {},
+                Ok(value) => panic!("unexpected element (expected={}, got={})", expected, value),
+                Err(e) => panic!("failed to pop element (error={:?})", e),
+            }
+        }
+        match ring.pop() {
+            Ok(_) => panic!("popped element from an empty ring buffer"),
+            Err(e) if e.code == ErrorCode::NoMessageAvailable => {},
+            Err(e) => panic!("unexpected error code (error={:?})", e),
+        }
+    }
+}
+
+/// Attempts to create a [`RingBuffer`] on an unmanaged raw array and drop it with elements left.
+#[test]
+fn test_from_raw_array() {
+    let mut storage: [MaybeUninit<Rc<()>>; CAPACITY] = [const { MaybeUninit::uninit() }; CAPACITY];
+    let array: RawArray<MaybeUninit<Rc<()>>> =
+        match unsafe { RawArray::from_raw_parts(storage.as_mut_ptr(), storage.len()) } {
+            Ok(array) => array,
+            Err(e) => panic!("failed to create array from raw parts (error={:?})", e),
+        };
+    let mut ring: RingBuffer<Rc<()>> = RingBuffer::from_raw_array(array);
+    let elem: Rc<()> = Rc::new(());
+
+    for _ in 0..CAPACITY - 1 {
+        if let Err((_, e)) = ring.push(elem.clone()) {
+            panic!("failed to push element (error={:?})", e);
+        }
+    }
+
+    // Check if elements are dropped along with the ring buffer.
+    drop(ring);
+    if Rc::strong_count(&elem) != 1 {
+        panic!("elements were not dropped (count={})", Rc::strong_count(&elem));
+    }
+}
+
+/// Attempts to exchange elements between two threads through a [`SpscRingBuffer`].
+#[test]
+fn test_spsc_stress() {
+    let mut ring: SpscRingBuffer<usize> = match SpscRingBuffer::new(CAPACITY) {
+        Ok(ring) => ring,
+        Err(e) => panic!("failed to create ring buffer (error={:?})", e),
+    };
+    let (mut producer, mut consumer): (SpscProducer<'_, usize>, SpscConsumer<'_, usize>) =
+        ring.split();
+
+    thread::scope(|scope| {
+        scope.spawn(move || {
+            for i in 0..NUM_ELEMENTS {
+                // Retry with the element that is handed back while the ring buffer is full.
+                let mut value: usize = i;
+                while let Err((rejected, _)) = producer.push(value) {
+                    value = rejected;
+                    thread::yield_now();
+                }
+            }
+        });
+
+        // Check if elements are received in order.
+        for expected in 0..NUM_ELEMENTS {
+            let value: usize = loop {
+                match consumer.pop() {
+                    Ok(value) => break value,
+                    Err(e) if e.code == ErrorCode::NoMessageAvailable => thread::yield_now(),
+                    Err(e) => panic!("unexpected error code (error={:?})", e),
+                }
+            };
+            if value != expected {
+                panic!("unexpected element (expected={}, got={})", expected, value);
+            }
+        }
+    });
+
+    if !ring.is_empty() {
+        panic!("ring buffer should be empty (len={})", ring.len());
+    }
+}
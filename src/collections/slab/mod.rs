@@ -7,6 +7,9 @@
 
 mod slab;
 
+#[cfg(test)]
+mod test;
+
 //==================================================================================================
 // Exports
 //==================================================================================================
@@ -7,7 +7,10 @@
 
 use crate::{
     collections::{
-        bitmap::Bitmap,
+        bitmap::{
+            Bitmap,
+            BitmapIter,
+        },
         raw_array::RawArray,
     },
     error::{
@@ -15,6 +18,7 @@ use crate::{
         ErrorCode,
     },
 };
+use ::core::mem;
 
 //==================================================================================================
 // Structures
@@ -28,11 +32,21 @@ use crate::{
 /// It has the following layout in memory:
 ///
 /// ```text
-/// +-------------------+--------------------------------------+
-/// | Index Blocks      | Data Blocks                          |
-/// +-------------------+--------------------------------------+
+/// +---------+-------+---------+--------------------------------------+------+
+/// | Padding | Index | Padding | Data Blocks                          | Tail |
+/// +---------+-------+---------+--------------------------------------+------+
 /// ```
 ///
+/// The index is aligned to a machine word, and data blocks are aligned to their size if it is a
+/// power of two, or to [`Slab::MIN_BLOCK_ALIGNMENT`] otherwise. Any bytes of the memory region that
+/// are left over past the last data block make up the tail, which is not used.
+///
+/// When the `slab-debug` feature is enabled, each data block is followed by a red zone, and free
+/// blocks are filled with a poison pattern. Both are checked whenever a block changes hands, so
+/// that buffer overruns and writes to free blocks are reported as
+/// [`ErrorCode::UncleanStructure`] errors. Corrupted blocks are never handed out again, and the
+/// address of the last one that was detected is reported by [`Slab::last_corrupted_block()`].
+///
 #[derive(Debug)]
 pub struct Slab {
     /// An index that keeps track of free blocks.
@@ -43,6 +57,28 @@ pub struct Slab {
     num_data_blocks: usize,
     /// Size of blocks in the slab.
     block_size: usize,
+    /// Alignment of blocks in the slab.
+    block_align: usize,
+    /// Distance between consecutive data blocks (in bytes).
+    stride: usize,
+    /// Number of bytes in the memory region that are used neither by the index nor by objects.
+    wasted_bytes: usize,
+    /// Address of the last corrupted block that was detected.
+    #[cfg(feature = "slab-debug")]
+    corrupted_block: Option<*mut u8>,
+}
+
+///
+/// # Description
+///
+/// An iterator over the addresses of blocks that are allocated in a [`Slab`].
+///
+#[derive(Debug)]
+pub struct SlabIter<'a> {
+    /// Slab allocator that is iterated over.
+    slab: &'a Slab,
+    /// Iterator over the indexes of allocated blocks.
+    blocks: BitmapIter<'a>,
 }
 
 //==================================================================================================
@@ -50,12 +86,31 @@ pub struct Slab {
 //==================================================================================================
 
 impl Slab {
+    /// Alignment of data blocks whose size is not a power of two (in bytes).
+    pub const MIN_BLOCK_ALIGNMENT: usize = 2 * mem::align_of::<usize>();
+
+    /// Minimum size of red zones placed after data blocks (in bytes).
+    #[cfg(feature = "slab-debug")]
+    pub const RED_ZONE_SIZE: usize = Self::MIN_BLOCK_ALIGNMENT;
+
+    /// Byte pattern that fills free data blocks.
+    #[cfg(feature = "slab-debug")]
+    pub const POISON_BYTE: u8 = 0x6b;
+
+    /// Byte pattern that fills red zones.
+    #[cfg(feature = "slab-debug")]
+    pub const RED_ZONE_BYTE: u8 = 0xbb;
+
     ///
     /// # Description
     ///
     /// Creates a new slab allocator on the memory region starting at `addr` with `len` bytes and
     /// block size of `block_size` bytes. The slab allocator is initialized with all blocks free.
     ///
+    /// If `block_size` is not a power of two, it is rounded up to a multiple of
+    /// [`Slab::MIN_BLOCK_ALIGNMENT`]. As many data blocks as possible are placed in the memory
+    /// region, and any bytes that are left over are reported by [`Slab::wasted_bytes()`].
+    ///
     /// # Parameters
     ///
     /// - `addr`: Start address of the memory region.
@@ -88,58 +143,204 @@ impl Slab {
         }
 
         // Check if block size is valid.
-        if block_size == 0 || block_size >= i32::MAX as usize || block_size > len {
+        if block_size == 0 || block_size >= i32::MAX as usize {
             return Err(Error::new(ErrorCode::InvalidArgument, "invalid block size"));
         }
 
-        // Check if the `block_size` is a power of two.
-        if block_size & (block_size - 1) != 0 {
-            return Err(Error::new(ErrorCode::InvalidArgument, "block size is not a power of two"));
-        }
-
-        // Check if `start_addr` is aligned to `block_size`.
-        if (addr as usize) % block_size != 0 {
-            return Err(Error::new(ErrorCode::InvalidArgument, "unaligned start address"));
-        }
+        // Compute size and alignment of data blocks.
+        let requested_size: usize = block_size;
+        let (block_size, block_align): (usize, usize) = if block_size.is_power_of_two() {
+            (block_size, block_size)
+        } else {
+            (align_up(block_size, Self::MIN_BLOCK_ALIGNMENT), Self::MIN_BLOCK_ALIGNMENT)
+        };
+        #[cfg(not(feature = "slab-debug"))]
+        let stride: usize = block_size;
+        // Reserve room for a red zone after each block, while preserving alignment.
+        #[cfg(feature = "slab-debug")]
+        let stride: usize = align_up(block_size + Self::RED_ZONE_SIZE, block_align);
 
         // Compute layout of the slab allocator.
-        let total_num_blocks: usize = len / block_size;
-        // info!("total number of blocks: {:?}", total_num_blocks);
-        if total_num_blocks % u8::BITS as usize != 0 {
-            return Err(Error::new(ErrorCode::InvalidArgument, "invalid number of blocks"));
-        }
-        let index_len: usize = total_num_blocks / u8::BITS as usize;
-        // info!("index length: {:?}", index_len);
-        let num_index_blocks: usize =
-            (index_len / block_size) + if index_len % block_size == 0 { 0 } else { 1 };
-        // info!("number of index blocks: {:?}", num_index_blocks);
-        let num_data_blocks: usize = total_num_blocks - num_index_blocks;
-        // info!("number of data blocks: {:?}", num_data_blocks);
-        let data_addr: *mut u8 = addr.add(num_index_blocks * block_size);
-
-        // Check if `data_addr` is aligned to `block_size`.
-        if (data_addr as usize) % block_size != 0 {
-            return Err(Error::new(ErrorCode::InvalidArgument, "unaligned data address"));
+        let start: usize = addr as usize;
+        let end: usize = start + len;
+        let index_addr: usize = align_up(start, mem::align_of::<usize>());
+        // Estimate the number of data blocks, accounting for one bit of index for each block.
+        let available: u64 = end.saturating_sub(index_addr) as u64;
+        let mut num_data_blocks: usize =
+            ((available * u8::BITS as u64) / (stride as u64 * u8::BITS as u64 + 1)) as usize;
+        // Shrink the estimate until both index and data blocks, along with padding, fit.
+        let (index_len, data_addr): (usize, usize) = loop {
+            if num_data_blocks == 0 {
+                return Err(Error::new(ErrorCode::InvalidArgument, "memory region is too small"));
+            }
+            let index_len: usize = Bitmap::words_for(num_data_blocks) * mem::size_of::<usize>();
+            let data_addr: usize = align_up(index_addr + index_len, block_align);
+            match data_addr.checked_add(num_data_blocks * stride) {
+                Some(data_end) if data_end <= end => break (index_len, data_addr),
+                _ => num_data_blocks -= 1,
+            }
+        };
+        let wasted_bytes: usize = len - index_len - num_data_blocks * requested_size;
+
+        // Instantiate index. It keeps track of data blocks only, so that bit `i` refers to the
+        // data block at `data_addr + i * stride`.
+        let storage: RawArray<usize> =
+            RawArray::from_raw_parts(index_addr as *mut usize, Bitmap::words_for(num_data_blocks))?;
+        let index: Bitmap = Bitmap::from_raw_array(storage, num_data_blocks)?;
+
+        let slab: Slab = Slab {
+            num_data_blocks,
+            block_size,
+            block_align,
+            stride,
+            data_addr: data_addr as *mut u8,
+            index,
+            wasted_bytes,
+            #[cfg(feature = "slab-debug")]
+            corrupted_block: None,
+        };
+
+        // Poison all blocks and arm their red zones.
+        #[cfg(feature = "slab-debug")]
+        for block in 0..num_data_blocks {
+            let block_addr: *mut u8 = slab.block_addr(block);
+            fill(block_addr, block_size, Self::POISON_BYTE);
+            fill(block_addr.add(block_size), stride - block_size, Self::RED_ZONE_BYTE);
         }
 
-        // Instantiate index.
-        let storage: RawArray<u8> = RawArray::from_raw_parts(addr, index_len)?;
-        let mut index: Bitmap = Bitmap::from_raw_array(storage);
+        Ok(slab)
+    }
 
-        // NOTE: The index is initialized with all blocks free, thus if we fail beyond this point
-        // the memory region is left in a modified state.
+    ///
+    /// # Description
+    ///
+    /// Returns the size of blocks in the slab allocator.
+    ///
+    /// # Returns
+    ///
+    /// The size of blocks in the slab allocator (in bytes).
+    ///
+    pub fn block_size(&self) -> usize {
+        self.block_size
+    }
 
-        // Initialize index.
-        for i in 0..num_index_blocks {
-            index.set(i)?;
+    ///
+    /// # Description
+    ///
+    /// Returns the alignment of blocks in the slab allocator.
+    ///
+    /// # Returns
+    ///
+    /// The alignment of blocks in the slab allocator (in bytes).
+    ///
+    pub fn block_alignment(&self) -> usize {
+        self.block_align
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the number of data blocks managed by the slab allocator.
+    ///
+    /// # Returns
+    ///
+    /// The number of data blocks managed by the slab allocator.
+    ///
+    pub fn capacity(&self) -> usize {
+        self.num_data_blocks
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the number of bytes in the memory region of the slab allocator that are used neither
+    /// by its index nor by objects. This includes padding, rounding of block sizes, and the tail of
+    /// the memory region.
+    ///
+    /// # Returns
+    ///
+    /// The number of bytes that are wasted in the memory region of the slab allocator.
+    ///
+    pub fn wasted_bytes(&self) -> usize {
+        self.wasted_bytes
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the number of data blocks that are free in the slab allocator.
+    ///
+    /// # Returns
+    ///
+    /// The number of data blocks that are free in the slab allocator.
+    ///
+    pub fn free_blocks(&self) -> usize {
+        self.index.count_zeros()
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the number of data blocks that are allocated in the slab allocator.
+    ///
+    /// # Returns
+    ///
+    /// The number of data blocks that are allocated in the slab allocator.
+    ///
+    pub fn used_blocks(&self) -> usize {
+        self.index.count_ones()
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Checks if a pointer lies in the memory region of data blocks managed by the slab allocator.
+    /// The block that the pointer refers to may be either free or allocated.
+    ///
+    /// # Parameters
+    ///
+    /// - `ptr`: Pointer to check.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the pointer lies in the memory region managed by the slab allocator, `false`
+    /// otherwise.
+    ///
+    pub fn contains(&self, ptr: *const u8) -> bool {
+        let start: usize = self.data_addr as usize;
+        let end: usize = start + self.num_data_blocks * self.stride;
+        (start..end).contains(&(ptr as usize))
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns an iterator over the addresses of blocks that are allocated in the slab allocator.
+    ///
+    /// # Returns
+    ///
+    /// An iterator over the addresses of blocks that are allocated in the slab allocator, in
+    /// ascending order.
+    ///
+    pub fn iter_allocated(&self) -> SlabIter<'_> {
+        SlabIter {
+            slab: self,
+            blocks: self.index.iter_set(),
         }
+    }
 
-        Ok(Slab {
-            num_data_blocks,
-            block_size,
-            data_addr,
-            index,
-        })
+    ///
+    /// # Description
+    ///
+    /// Returns the address of the last corrupted block that was detected in the slab allocator.
+    ///
+    /// # Returns
+    ///
+    /// The address of the last corrupted block that was detected, if any.
+    ///
+    #[cfg(feature = "slab-debug")]
+    pub fn last_corrupted_block(&self) -> Option<*mut u8> {
+        self.corrupted_block
     }
 
     ///
@@ -154,8 +355,20 @@ impl Slab {
     ///
     pub fn allocate(&mut self) -> Result<*mut u8, Error> {
         let block: usize = self.index.alloc()?;
-        // Safety: the start and resulting addresses are valid.
-        let block_addr: *mut u8 = unsafe { self.data_addr.add(block * self.block_size) };
+        let block_addr: *mut u8 = self.block_addr(block);
+
+        // Check if the block was modified while free. If so, it is kept allocated, so that it is
+        // never handed out.
+        #[cfg(feature = "slab-debug")]
+        {
+            // Safety: the block lies within the bounds of the slab.
+            if !unsafe { check(block_addr, self.block_size, Self::POISON_BYTE) } {
+                self.corrupted_block = Some(block_addr);
+                return Err(Error::new(ErrorCode::UncleanStructure, "free block was modified"));
+            }
+            self.check_red_zone(block_addr)?;
+        }
+
         Ok(block_addr)
     }
 
@@ -174,25 +387,152 @@ impl Slab {
     ///
     pub fn deallocate(&mut self, ptr: *const u8) -> Result<(), Error> {
         // Check if the pointer lies in a memory region that is not managed by this allocator.
-        // Safety: the start and resulting addresses are valid.
-        if ptr < self.data_addr
-            || ptr >= unsafe { self.data_addr.add(self.num_data_blocks * self.block_size) }
-        {
+        if !self.contains(ptr) {
             return Err(Error::new(ErrorCode::BadAddress, "pointer out of bounds"));
         }
 
         // Compute the block index.
         // Safety: we have already checked that ptr is within the bounds of the slab.
-        let index: usize = unsafe { ptr.sub_ptr(self.data_addr) } / self.block_size;
+        let index: usize = unsafe { ptr.sub_ptr(self.data_addr) } / self.stride;
 
         // Check if the block is already free.
         if !self.index.test(index)? {
             return Err(Error::new(ErrorCode::BadAddress, "block is already free"));
         }
 
+        // Check if the block overran into its red zone. If so, it is kept allocated, so that it is
+        // never handed out again. Otherwise, poison it.
+        #[cfg(feature = "slab-debug")]
+        {
+            let block_addr: *mut u8 = self.block_addr(index);
+            self.check_red_zone(block_addr)?;
+            // Safety: the block lies within the bounds of the slab.
+            unsafe { fill(block_addr, self.block_size, Self::POISON_BYTE) };
+        }
+
         // Free the block.
         self.index.clear(index)?;
 
         Ok(())
     }
+
+    ///
+    /// # Description
+    ///
+    /// Computes the address of a data block.
+    ///
+    /// # Parameters
+    ///
+    /// - `block`: Index of the target data block.
+    ///
+    /// # Returns
+    ///
+    /// The address of the target data block.
+    ///
+    fn block_addr(&self, block: usize) -> *mut u8 {
+        // Safety: callers ensure that the block lies within the bounds of the slab.
+        unsafe { self.data_addr.add(block * self.stride) }
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Checks if the red zone of a data block is intact. If not, the block is recorded as the last
+    /// corrupted block.
+    ///
+    /// # Parameters
+    ///
+    /// - `block_addr`: Address of the target data block.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, `Ok(())` is returned. Upon failure, an error is returned instead.
+    ///
+    #[cfg(feature = "slab-debug")]
+    fn check_red_zone(&mut self, block_addr: *mut u8) -> Result<(), Error> {
+        let red_zone_len: usize = self.stride - self.block_size;
+        // Safety: the red zone lies within the bounds of the slab.
+        if !unsafe { check(block_addr.add(self.block_size), red_zone_len, Self::RED_ZONE_BYTE) } {
+            self.corrupted_block = Some(block_addr);
+            return Err(Error::new(ErrorCode::UncleanStructure, "red zone was overwritten"));
+        }
+        Ok(())
+    }
+}
+
+impl Iterator for SlabIter<'_> {
+    type Item = *mut u8;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let block: usize = self.blocks.next()?;
+        Some(self.slab.block_addr(block))
+    }
+}
+
+//==================================================================================================
+// Standalone Functions
+//==================================================================================================
+
+///
+/// # Description
+///
+/// Aligns a value up to a power-of-two alignment.
+///
+/// # Parameters
+///
+/// - `value`: Value to align.
+/// - `align`: Alignment (power of two).
+///
+/// # Returns
+///
+/// The smallest multiple of `align` that is greater than or equal to `value`.
+///
+fn align_up(value: usize, align: usize) -> usize {
+    (value + align - 1) & !(align - 1)
+}
+
+///
+/// # Description
+///
+/// Fills a memory region with a byte pattern.
+///
+/// # Parameters
+///
+/// - `addr`: Start address of the memory region.
+/// - `len`: Length of the memory region in bytes.
+/// - `pattern`: Byte pattern.
+///
+/// # Safety
+///
+/// This function is unsafe because it assumes that the memory region is valid.
+///
+#[cfg(feature = "slab-debug")]
+unsafe fn fill(addr: *mut u8, len: usize, pattern: u8) {
+    ::core::ptr::write_bytes(addr, pattern, len);
+}
+
+///
+/// # Description
+///
+/// Checks if a memory region is filled with a byte pattern.
+///
+/// # Parameters
+///
+/// - `addr`: Start address of the memory region.
+/// - `len`: Length of the memory region in bytes.
+/// - `pattern`: Byte pattern.
+///
+/// # Returns
+///
+/// `true` if all bytes in the memory region match the pattern, `false` otherwise.
+///
+/// # Safety
+///
+/// This function is unsafe because it assumes that the memory region is valid.
+///
+#[cfg(feature = "slab-debug")]
+unsafe fn check(addr: *const u8, len: usize, pattern: u8) -> bool {
+    ::core::slice::from_raw_parts(addr, len)
+        .iter()
+        .all(|byte| *byte == pattern)
 }
@@ -0,0 +1,264 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::{
+    collections::Slab,
+    error::ErrorCode,
+};
+use ::alloc::vec::Vec;
+use ::core::mem::size_of;
+
+//==================================================================================================
+// Unit Tests
+//==================================================================================================
+
+/// Size of memory regions used in tests.
+const REGION_SIZE: usize = 4096;
+
+/// Size of blocks used in tests.
+const BLOCK_SIZE: usize = 64;
+
+/// A memory region that is suitably aligned for slab allocators used in tests.
+#[repr(C, align(4096))]
+struct Region([u8; REGION_SIZE]);
+
+/// Helper test function that creates a [`Slab`] on a memory region.
+fn test_helper_create_slab(region: &mut Region, block_size: usize) -> Slab {
+    match unsafe { Slab::from_raw_parts(region.0.as_mut_ptr(), REGION_SIZE, block_size) } {
+        Ok(slab) => slab,
+        Err(e) => panic!("failed to create slab (error={:?})", e),
+    }
+}
+
+/// Attempts to allocate all blocks of a [`Slab`] and check that they lie within the region.
+#[test]
+fn test_allocate_all_blocks() {
+    let mut region: Region = Region([0; REGION_SIZE]);
+    let start: usize = region.0.as_ptr() as usize;
+    let mut slab: Slab = test_helper_create_slab(&mut region, BLOCK_SIZE);
+
+    // Check statistics of an empty slab.
+    if slab.block_size() != BLOCK_SIZE
+        || slab.free_blocks() != slab.capacity()
+        || slab.used_blocks() != 0
+    {
+        panic!("unexpected statistics of an empty slab");
+    }
+
+    // Allocate all blocks.
+    let mut blocks: Vec<*mut u8> = Vec::new();
+    for _ in 0..slab.capacity() {
+        match slab.allocate() {
+            Ok(ptr) => blocks.push(ptr),
+            Err(e) => panic!("failed to allocate block (error={:?})", e),
+        }
+    }
+
+    // Check if all blocks lie within the region and are owned by the slab.
+    for ptr in blocks.iter() {
+        let addr: usize = *ptr as usize;
+        if addr < start || addr + BLOCK_SIZE > start + REGION_SIZE || !slab.contains(*ptr) {
+            panic!("block lies out of the memory region (addr={:#x})", addr);
+        }
+    }
+
+    // Check if the slab is full.
+    match slab.allocate() {
+        Ok(_) => panic!("allocated block from a full slab"),
+        Err(e) if e.code == ErrorCode::OutOfMemory => {},
+        Err(e) => panic!("unexpected error code (error={:?})", e),
+    }
+    if slab.free_blocks() != 0 || slab.used_blocks() != slab.capacity() {
+        panic!("unexpected statistics of a full slab");
+    }
+
+    // Free all blocks.
+    for ptr in blocks.iter() {
+        if let Err(e) = slab.deallocate(*ptr) {
+            panic!("failed to free block (error={:?})", e);
+        }
+    }
+    if slab.used_blocks() != 0 {
+        panic!("unexpected statistics of an empty slab");
+    }
+}
+
+/// Attempts to iterate over allocated blocks of a [`Slab`].
+#[test]
+fn test_iter_allocated() {
+    let mut region: Region = Region([0; REGION_SIZE]);
+    let mut slab: Slab = test_helper_create_slab(&mut region, BLOCK_SIZE);
+
+    // Allocate some blocks and free one of them.
+    let mut blocks: Vec<*mut u8> = Vec::new();
+    for _ in 0..4 {
+        match slab.allocate() {
+            Ok(ptr) => blocks.push(ptr),
+            Err(e) => panic!("failed to allocate block (error={:?})", e),
+        }
+    }
+    let ptr: *mut u8 = blocks.remove(1);
+    if let Err(e) = slab.deallocate(ptr) {
+        panic!("failed to free block (error={:?})", e);
+    }
+
+    // Check if only allocated blocks are reported.
+    if !slab.iter_allocated().eq(blocks.iter().copied()) {
+        panic!("unexpected allocated blocks");
+    }
+}
+
+/// Attempts to check ownership of and free pointers that do not belong to a [`Slab`].
+#[test]
+fn test_foreign_pointers() {
+    let mut region: Region = Region([0; REGION_SIZE]);
+    let start: *mut u8 = region.0.as_mut_ptr();
+    let mut slab: Slab = test_helper_create_slab(&mut region, BLOCK_SIZE);
+    let outside: u8 = 0;
+
+    // The first block of the region holds the index, thus it is not owned by the slab.
+    if slab.contains(start) || slab.contains(&outside) {
+        panic!("slab claims ownership of a foreign pointer");
+    }
+
+    match slab.deallocate(&outside) {
+        Ok(_) => panic!("freed foreign pointer"),
+        Err(e) if e.code == ErrorCode::BadAddress => {},
+        Err(e) => panic!("unexpected error code (error={:?})", e),
+    }
+}
+
+/// Attempts to create a [`Slab`] with a block size that is not a power of two.
+#[test]
+fn test_non_power_of_two_block_size() {
+    const OBJECT_SIZE: usize = 48;
+    let mut region: Region = Region([0; REGION_SIZE]);
+    let start: usize = region.0.as_ptr() as usize;
+    let mut slab: Slab = test_helper_create_slab(&mut region, OBJECT_SIZE);
+
+    // Check if the block size was kept, as it is already suitably aligned.
+    if slab.block_size() != OBJECT_SIZE {
+        panic!("unexpected block size (expected={}, got={})", OBJECT_SIZE, slab.block_size());
+    }
+
+    // Check if index, objects and wasted bytes account for the whole memory region.
+    let index_len: usize = slab.capacity().div_ceil(usize::BITS as usize) * size_of::<usize>();
+    if index_len + slab.capacity() * OBJECT_SIZE + slab.wasted_bytes() != REGION_SIZE {
+        panic!("unexpected number of wasted bytes (got={})", slab.wasted_bytes());
+    }
+    // Check if the tail is smaller than a block, unless red zones take up space.
+    #[cfg(not(feature = "slab-debug"))]
+    if slab.wasted_bytes() >= OBJECT_SIZE + Slab::MIN_BLOCK_ALIGNMENT + size_of::<usize>() {
+        panic!("too many wasted bytes (got={})", slab.wasted_bytes());
+    }
+
+    // Allocate all blocks and check if they are aligned and lie within the region.
+    for _ in 0..slab.capacity() {
+        let addr: usize = match slab.allocate() {
+            Ok(ptr) => ptr as usize,
+            Err(e) => panic!("failed to allocate block (error={:?})", e),
+        };
+        if addr % Slab::MIN_BLOCK_ALIGNMENT != 0 || addr + OBJECT_SIZE > start + REGION_SIZE {
+            panic!("misplaced block (addr={:#x})", addr);
+        }
+    }
+}
+
+/// Attempts to create a [`Slab`] on a memory region with unaligned bounds.
+#[test]
+fn test_unaligned_region() {
+    let mut region: Region = Region([0; REGION_SIZE]);
+    let len: usize = REGION_SIZE - 2 * BLOCK_SIZE - 3;
+    let ptr: *mut u8 = region.0[1..].as_mut_ptr();
+    let mut slab: Slab = match unsafe { Slab::from_raw_parts(ptr, len, BLOCK_SIZE) } {
+        Ok(slab) => slab,
+        Err(e) => panic!("failed to create slab (error={:?})", e),
+    };
+
+    // Allocate all blocks and check if they are aligned and lie within the region.
+    for _ in 0..slab.capacity() {
+        let addr: usize = match slab.allocate() {
+            Ok(ptr) => ptr as usize,
+            Err(e) => panic!("failed to allocate block (error={:?})", e),
+        };
+        if addr % BLOCK_SIZE != 0 || addr < ptr as usize || addr + BLOCK_SIZE > ptr as usize + len {
+            panic!("misplaced block (addr={:#x})", addr);
+        }
+    }
+}
+
+/// Attempts to create a [`Slab`] on a memory region that is too small.
+#[test]
+fn test_region_too_small() {
+    let mut region: Region = Region([0; REGION_SIZE]);
+
+    match unsafe { Slab::from_raw_parts(region.0.as_mut_ptr(), BLOCK_SIZE, BLOCK_SIZE) } {
+        Ok(_) => panic!("created slab on a memory region that is too small"),
+        Err(e) if e.code == ErrorCode::InvalidArgument => {},
+        Err(e) => panic!("unexpected error code (error={:?})", e),
+    }
+}
+
+/// Attempts to detect a write to a free block of a [`Slab`].
+#[test]
+#[cfg(feature = "slab-debug")]
+fn test_detect_use_after_free() {
+    let mut region: Region = Region([0; REGION_SIZE]);
+    let mut slab: Slab = test_helper_create_slab(&mut region, BLOCK_SIZE);
+
+    // Allocate and release a block, and then write to it.
+    let ptr: *mut u8 = match slab.allocate() {
+        Ok(ptr) => ptr,
+        Err(e) => panic!("failed to allocate block (error={:?})", e),
+    };
+    if let Err(e) = slab.deallocate(ptr) {
+        panic!("failed to deallocate block (error={:?})", e);
+    }
+    unsafe { ptr.add(BLOCK_SIZE / 2).write(0) };
+
+    // Check if the corruption is detected once the block is allocated again.
+    match slab.allocate() {
+        Ok(_) => panic!("allocated a corrupted block"),
+        Err(e) if e.code == ErrorCode::UncleanStructure => {},
+        Err(e) => panic!("unexpected error code (error={:?})", e),
+    }
+    if slab.last_corrupted_block() != Some(ptr) {
+        panic!("unexpected corrupted block (got={:?})", slab.last_corrupted_block());
+    }
+
+    // Check if the corrupted block is never handed out again.
+    match slab.allocate() {
+        Ok(other) if other != ptr => {},
+        Ok(_) => panic!("allocated a corrupted block"),
+        Err(e) => panic!("failed to allocate block (error={:?})", e),
+    }
+}
+
+/// Attempts to detect a buffer overrun in a block of a [`Slab`].
+#[test]
+#[cfg(feature = "slab-debug")]
+fn test_detect_buffer_overrun() {
+    let mut region: Region = Region([0; REGION_SIZE]);
+    let mut slab: Slab = test_helper_create_slab(&mut region, BLOCK_SIZE);
+
+    // Allocate a block and write past its end.
+    let ptr: *mut u8 = match slab.allocate() {
+        Ok(ptr) => ptr,
+        Err(e) => panic!("failed to allocate block (error={:?})", e),
+    };
+    unsafe { ptr.add(BLOCK_SIZE).write(0) };
+
+    // Check if the corruption is detected once the block is released.
+    match slab.deallocate(ptr) {
+        Ok(_) => panic!("deallocated a corrupted block"),
+        Err(e) if e.code == ErrorCode::UncleanStructure => {},
+        Err(e) => panic!("unexpected error code (error={:?})", e),
+    }
+    if slab.last_corrupted_block() != Some(ptr) {
+        panic!("unexpected corrupted block (got={:?})", slab.last_corrupted_block());
+    }
+}
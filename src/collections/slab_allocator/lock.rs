@@ -0,0 +1,99 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use ::core::{
+    hint,
+    sync::atomic::{
+        AtomicBool,
+        Ordering,
+    },
+};
+
+//==================================================================================================
+// Traits
+//==================================================================================================
+
+///
+/// # Description
+///
+/// A lock that guards a critical section.
+///
+/// # Safety
+///
+/// Implementations must ensure that at most one execution flow holds the lock at a time. Locks
+/// that are used in interrupt context should also mask interrupts while the lock is held.
+///
+pub unsafe trait RawLock {
+    ///
+    /// # Description
+    ///
+    /// Acquires the lock, waiting until it becomes available.
+    ///
+    fn lock(&self);
+
+    ///
+    /// # Description
+    ///
+    /// Releases the lock.
+    ///
+    fn unlock(&self);
+}
+
+//==================================================================================================
+// Structures
+//==================================================================================================
+
+///
+/// # Description
+///
+/// A lock that busy waits until it becomes available.
+///
+#[derive(Debug, Default)]
+pub struct SpinLock {
+    /// Whether the lock is held.
+    locked: AtomicBool,
+}
+
+//==================================================================================================
+// Implementations
+//==================================================================================================
+
+impl SpinLock {
+    ///
+    /// # Description
+    ///
+    /// Creates a new spin lock. The lock is initially released.
+    ///
+    /// # Returns
+    ///
+    /// A new spin lock.
+    ///
+    pub const fn new() -> Self {
+        Self {
+            locked: AtomicBool::new(false),
+        }
+    }
+}
+
+unsafe impl RawLock for SpinLock {
+    fn lock(&self) {
+        while self
+            .locked
+            .compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            // Wait until the lock looks released before attempting to acquire it again.
+            while self.locked.load(Ordering::Relaxed) {
+                hint::spin_loop();
+            }
+        }
+    }
+
+    fn unlock(&self) {
+        self.locked.store(false, Ordering::Release);
+    }
+}
@@ -0,0 +1,331 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::{
+    collections::{
+        slab::Slab,
+        slab_allocator::{
+            RawLock,
+            SpinLock,
+        },
+    },
+    error::{
+        Error,
+        ErrorCode,
+    },
+};
+use ::core::{
+    alloc::{
+        GlobalAlloc,
+        Layout,
+    },
+    cell::UnsafeCell,
+    ptr,
+};
+
+//==================================================================================================
+// Structures
+//==================================================================================================
+
+///
+/// # Description
+///
+/// A general-purpose allocator that manages one [`Slab`] per power-of-two size class.
+///
+/// Requests are routed to the slab with the smallest blocks that fit them. If that slab is full,
+/// slabs with larger blocks are tried in turn. All slabs are guarded by a single lock, so that the
+/// allocator can be shared and used as the global allocator.
+///
+#[derive(Debug)]
+pub struct SlabAllocator<L: RawLock = SpinLock> {
+    /// Lock that guards slabs.
+    lock: L,
+    /// Slabs, indexed by size class.
+    slabs: UnsafeCell<[Option<Slab>; SlabAllocator::NUM_SIZE_CLASSES]>,
+}
+
+// SAFETY: slabs are only accessed while the lock is held.
+unsafe impl<L: RawLock + Sync> Sync for SlabAllocator<L> {}
+
+///
+/// # Description
+///
+/// A guard that releases a lock when dropped, so that the lock is released even if the critical
+/// section panics.
+///
+struct LockGuard<'a, L: RawLock> {
+    /// Lock that is held.
+    lock: &'a L,
+}
+
+//==================================================================================================
+// Implementations
+//==================================================================================================
+
+impl SlabAllocator {
+    /// Number of size classes.
+    pub const NUM_SIZE_CLASSES: usize = 9;
+
+    /// Block size of the smallest size class (in bytes).
+    pub const MIN_BLOCK_SIZE: usize = 16;
+
+    /// Block size of the largest size class (in bytes).
+    pub const MAX_BLOCK_SIZE: usize = Self::MIN_BLOCK_SIZE << (Self::NUM_SIZE_CLASSES - 1);
+}
+
+impl<L: RawLock> SlabAllocator<L> {
+    ///
+    /// # Description
+    ///
+    /// Creates a new slab allocator with no memory attached to it. Allocation requests fail until
+    /// [`SlabAllocator::init()`] is called.
+    ///
+    /// # Parameters
+    ///
+    /// - `lock`: Lock that guards the allocator.
+    ///
+    /// # Returns
+    ///
+    /// A new slab allocator.
+    ///
+    pub const fn new(lock: L) -> Self {
+        Self {
+            lock,
+            slabs: UnsafeCell::new([const { None }; SlabAllocator::NUM_SIZE_CLASSES]),
+        }
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Attaches a memory region to the slab allocator. The region is split evenly among size
+    /// classes, and any slab that was previously attached is replaced.
+    ///
+    /// # Parameters
+    ///
+    /// - `addr`: Start address of the memory region. It must be aligned to
+    ///   [`SlabAllocator::MAX_BLOCK_SIZE`].
+    /// - `len`: Length of the memory region in bytes.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, `Ok(())` is returned. Upon failure, an error is returned instead and the slab
+    /// allocator is left unmodified.
+    ///
+    /// # Safety
+    ///
+    /// This function is unsafe for the following reasons:
+    /// - It assumes that the memory region starting at `addr` with `len` bytes is valid.
+    /// - It assumes that no block of previously attached slabs is in use.
+    ///
+    pub unsafe fn init(&self, addr: *mut u8, len: usize) -> Result<(), Error> {
+        // Check if the start address is aligned to the largest block size.
+        if (addr as usize) % SlabAllocator::MAX_BLOCK_SIZE != 0 {
+            return Err(Error::new(ErrorCode::InvalidArgument, "unaligned start address"));
+        }
+
+        // Compute the length of the memory region of each slab. It is kept a multiple of the largest
+        // block size, so that every slab starts at an address that is aligned to its block size.
+        let slab_len: usize = (len / SlabAllocator::NUM_SIZE_CLASSES)
+            / SlabAllocator::MAX_BLOCK_SIZE
+            * SlabAllocator::MAX_BLOCK_SIZE;
+        if slab_len == 0 {
+            return Err(Error::new(ErrorCode::InvalidArgument, "memory region is too small"));
+        }
+
+        // Create slabs.
+        let mut slabs: [Option<Slab>; SlabAllocator::NUM_SIZE_CLASSES] =
+            [const { None }; SlabAllocator::NUM_SIZE_CLASSES];
+        for (class, slab) in slabs.iter_mut().enumerate() {
+            let slab_addr: *mut u8 = addr.add(class * slab_len);
+            *slab = Some(Slab::from_raw_parts(slab_addr, slab_len, Self::block_size(class))?);
+        }
+
+        self.with_slabs(|current| *current = slabs);
+
+        Ok(())
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Allocates a block of memory that fits a given layout.
+    ///
+    /// # Parameters
+    ///
+    /// - `layout`: Layout of the memory to allocate.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, a pointer to the allocated block is returned. Upon failure, an error is
+    /// returned instead.
+    ///
+    pub fn allocate(&self, layout: Layout) -> Result<*mut u8, Error> {
+        let class: usize = match Self::size_class(layout) {
+            Some(class) => class,
+            None => return Err(Error::new(ErrorCode::InvalidArgument, "layout is too large")),
+        };
+
+        self.with_slabs(|slabs| {
+            // Try the smallest size class that fits, then fall back to larger ones.
+            for slab in slabs[class..].iter_mut().flatten() {
+                if let Ok(ptr) = slab.allocate() {
+                    return Ok(ptr);
+                }
+            }
+
+            Err(Error::new(ErrorCode::OutOfMemory, "out of memory"))
+        })
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Frees a block of memory that was allocated by the slab allocator.
+    ///
+    /// # Parameters
+    ///
+    /// - `ptr`: Pointer to the block to free.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, `Ok(())` is returned. Upon failure, an error is returned instead.
+    ///
+    pub fn deallocate(&self, ptr: *const u8) -> Result<(), Error> {
+        self.with_slabs(|slabs| {
+            for slab in slabs.iter_mut().flatten() {
+                if slab.contains(ptr) {
+                    return slab.deallocate(ptr);
+                }
+            }
+
+            Err(Error::new(ErrorCode::BadAddress, "pointer out of bounds"))
+        })
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Checks if a pointer lies in the memory region managed by the slab allocator.
+    ///
+    /// # Parameters
+    ///
+    /// - `ptr`: Pointer to check.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the pointer lies in the memory region managed by the slab allocator, `false`
+    /// otherwise.
+    ///
+    pub fn contains(&self, ptr: *const u8) -> bool {
+        self.with_slabs(|slabs| slabs.iter().flatten().any(|slab| slab.contains(ptr)))
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the size class that fits a given layout.
+    ///
+    /// # Parameters
+    ///
+    /// - `layout`: Layout to fit.
+    ///
+    /// # Returns
+    ///
+    /// The index of the smallest size class whose blocks fit the layout, if any. Blocks are aligned
+    /// to their size, thus the alignment of the layout is taken into account as well.
+    ///
+    fn size_class(layout: Layout) -> Option<usize> {
+        let size: usize = layout
+            .size()
+            .max(layout.align())
+            .max(SlabAllocator::MIN_BLOCK_SIZE)
+            .checked_next_power_of_two()?;
+
+        if size > SlabAllocator::MAX_BLOCK_SIZE {
+            return None;
+        }
+
+        Some((size.trailing_zeros() - SlabAllocator::MIN_BLOCK_SIZE.trailing_zeros()) as usize)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the block size of a given size class.
+    ///
+    /// # Parameters
+    ///
+    /// - `class`: Index of the size class.
+    ///
+    /// # Returns
+    ///
+    /// The block size of the size class (in bytes).
+    ///
+    fn block_size(class: usize) -> usize {
+        SlabAllocator::MIN_BLOCK_SIZE << class
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Runs a function on the slabs, while holding the lock.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: Function to run.
+    ///
+    /// # Returns
+    ///
+    /// The value returned by `f`.
+    ///
+    fn with_slabs<R>(
+        &self,
+        f: impl FnOnce(&mut [Option<Slab>; SlabAllocator::NUM_SIZE_CLASSES]) -> R,
+    ) -> R {
+        let _guard: LockGuard<L> = LockGuard::new(&self.lock);
+        // Safety: slabs are only accessed while the lock is held.
+        f(unsafe { &mut *self.slabs.get() })
+    }
+}
+
+impl<'a, L: RawLock> LockGuard<'a, L> {
+    ///
+    /// # Description
+    ///
+    /// Acquires a lock and creates a guard that releases it.
+    ///
+    /// # Parameters
+    ///
+    /// - `lock`: Lock to acquire.
+    ///
+    /// # Returns
+    ///
+    /// A guard that releases the lock when dropped.
+    ///
+    fn new(lock: &'a L) -> Self {
+        lock.lock();
+        Self { lock }
+    }
+}
+
+impl<L: RawLock> Drop for LockGuard<'_, L> {
+    fn drop(&mut self) {
+        self.lock.unlock();
+    }
+}
+
+unsafe impl<L: RawLock> GlobalAlloc for SlabAllocator<L> {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        self.allocate(layout).unwrap_or(ptr::null_mut())
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, _layout: Layout) {
+        // NOTE: errors cannot be reported from here, and they only arise from invalid pointers.
+        let _ = self.deallocate(ptr);
+    }
+}
@@ -0,0 +1,168 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+extern crate std;
+
+use crate::{
+    collections::{
+        SlabAllocator,
+        SpinLock,
+    },
+    error::ErrorCode,
+};
+use ::core::alloc::{
+    GlobalAlloc,
+    Layout,
+};
+use std::alloc::System;
+
+//==================================================================================================
+// Structures
+//==================================================================================================
+
+/// Size of the memory region that backs a test slab allocator.
+const HEAP_SIZE: usize = 1 << 20;
+
+/// A memory region that backs a test slab allocator, released when dropped.
+struct TestHeap {
+    /// Start address of the memory region.
+    ptr: *mut u8,
+    /// Layout of the memory region.
+    layout: Layout,
+}
+
+//==================================================================================================
+// Implementations
+//==================================================================================================
+
+impl Drop for TestHeap {
+    fn drop(&mut self) {
+        unsafe { System.dealloc(self.ptr, self.layout) }
+    }
+}
+
+//==================================================================================================
+// Standalone Functions
+//==================================================================================================
+
+/// Helper test function that runs a function on a slab allocator backed by a fresh memory region.
+fn test_helper_with_slab_allocator<R>(f: impl FnOnce(&SlabAllocator) -> R) -> R {
+    let layout: Layout = match Layout::from_size_align(HEAP_SIZE, SlabAllocator::MAX_BLOCK_SIZE) {
+        Ok(layout) => layout,
+        Err(_) => panic!("invalid layout"),
+    };
+    let ptr: *mut u8 = unsafe { System.alloc(layout) };
+    if ptr.is_null() {
+        panic!("failed to allocate memory region");
+    }
+
+    // NOTE: the memory region is released even if `f` panics.
+    let heap: TestHeap = TestHeap { ptr, layout };
+    let allocator: SlabAllocator = SlabAllocator::new(SpinLock::new());
+    if let Err(e) = unsafe { allocator.init(heap.ptr, HEAP_SIZE) } {
+        panic!("failed to initialize slab allocator (error={:?})", e);
+    }
+
+    f(&allocator)
+}
+
+//==================================================================================================
+// Unit Tests
+//==================================================================================================
+
+/// Attempts to grow a memory block through the [`GlobalAlloc`] interface of a [`SlabAllocator`].
+#[test]
+fn test_global_alloc_realloc() {
+    test_helper_with_slab_allocator(|allocator| {
+        let mut layout: Layout = Layout::new::<u32>();
+        let mut ptr: *mut u32 = unsafe { allocator.alloc(layout) } as *mut u32;
+        if ptr.is_null() || !allocator.contains(ptr as *const u8) {
+            panic!("memory was not allocated by the slab allocator");
+        }
+
+        // Grow the block through several size classes, as a vector would.
+        for len in 1..1000 {
+            unsafe { ptr.add(len - 1).write(len as u32) };
+            let new_layout: Layout = match Layout::array::<u32>(len + 1) {
+                Ok(layout) => layout,
+                Err(_) => panic!("invalid layout (len={})", len + 1),
+            };
+            ptr =
+                unsafe { allocator.realloc(ptr as *mut u8, layout, new_layout.size()) } as *mut u32;
+            layout = new_layout;
+            if ptr.is_null() || !allocator.contains(ptr as *const u8) {
+                panic!("memory was not reallocated by the slab allocator (len={})", len + 1);
+            }
+        }
+
+        // Check if contents survived reallocations.
+        for i in 0..999 {
+            if unsafe { ptr.add(i).read() } != i as u32 + 1 {
+                panic!("contents were corrupted (index={})", i);
+            }
+        }
+
+        unsafe { allocator.dealloc(ptr as *mut u8, layout) };
+    });
+}
+
+/// Attempts to allocate memory with various layouts from a [`SlabAllocator`].
+#[test]
+fn test_allocate_layouts() {
+    test_helper_with_slab_allocator(|allocator| {
+        for (size, align) in [(1, 1), (16, 16), (17, 8), (24, 64), (4096, 4096)] {
+            let layout: Layout = match Layout::from_size_align(size, align) {
+                Ok(layout) => layout,
+                Err(_) => panic!("invalid layout (size={}, align={})", size, align),
+            };
+
+            // Allocate memory.
+            let ptr: *mut u8 = match allocator.allocate(layout) {
+                Ok(ptr) => ptr,
+                Err(e) => panic!("failed to allocate memory (size={}, error={:?})", size, e),
+            };
+
+            // Check if the memory is suitably aligned.
+            if (ptr as usize) % align != 0 {
+                panic!("unaligned allocation (ptr={:?}, align={})", ptr, align);
+            }
+
+            // Free memory.
+            if let Err(e) = allocator.deallocate(ptr) {
+                panic!("failed to free memory (error={:?})", e);
+            }
+        }
+    });
+}
+
+/// Attempts to allocate memory that does not fit any size class of a [`SlabAllocator`].
+#[test]
+fn test_allocate_too_large() {
+    let layout: Layout = match Layout::from_size_align(SlabAllocator::MAX_BLOCK_SIZE + 1, 1) {
+        Ok(layout) => layout,
+        Err(_) => panic!("invalid layout"),
+    };
+
+    test_helper_with_slab_allocator(|allocator| match allocator.allocate(layout) {
+        Ok(_) => panic!("allocated memory that does not fit any size class"),
+        Err(e) if e.code == ErrorCode::InvalidArgument => {},
+        Err(e) => panic!("unexpected error code (error={:?})", e),
+    });
+}
+
+/// Attempts to allocate memory from a [`SlabAllocator`] that has no memory attached to it.
+#[test]
+fn test_allocate_uninitialized() {
+    let allocator: SlabAllocator = SlabAllocator::new(SpinLock::new());
+    let layout: Layout = Layout::new::<u64>();
+
+    match allocator.allocate(layout) {
+        Ok(_) => panic!("allocated memory from an uninitialized slab allocator"),
+        Err(e) if e.code == ErrorCode::OutOfMemory => {},
+        Err(e) => panic!("unexpected error code (error={:?})", e),
+    }
+}
@@ -15,7 +15,7 @@ use ::core::fmt::Debug;
 // Structures
 //==================================================================================================
 
-#[derive(Default, Clone, Eq, PartialEq)]
+#[derive(Default, Clone, Eq, PartialEq, Hash)]
 pub struct EventDescriptor(usize);
 
 //==================================================================================================
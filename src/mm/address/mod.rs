@@ -5,20 +5,29 @@
 // Modules
 //==================================================================================================
 
+mod phys;
+mod translate;
 mod virt;
 
+#[cfg(test)]
+mod test;
+
 //==================================================================================================
 // Exports
 //==================================================================================================
 
+pub use phys::*;
+pub use translate::*;
 pub use virt::*;
 
 //==================================================================================================
 // Imports
 //==================================================================================================
 
-use crate::mm::Alignment;
-use crate::error::Error;
+use crate::{
+    error::Error,
+    mm::Alignment,
+};
 
 //==================================================================================================
 // Traits
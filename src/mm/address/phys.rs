@@ -0,0 +1,191 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::{
+    error::{
+        Error,
+        ErrorCode,
+    },
+    mm::{
+        self,
+        Address,
+        Alignment,
+    },
+};
+
+//==================================================================================================
+// Structures
+//==================================================================================================
+
+///
+/// # Description
+///
+/// A type that represents a physical address.
+///
+/// Physical addresses are limited to `WIDTH` bits, which defaults to the width of a machine word.
+/// Addresses that do not fit in this width are rejected.
+///
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PhysicalAddress<const WIDTH: u32 = { usize::BITS }>(usize);
+
+//==================================================================================================
+// Implementations
+//==================================================================================================
+
+impl<const WIDTH: u32> PhysicalAddress<WIDTH> {
+    /// Maximum raw value of a physical address.
+    const MAX_ADDR: usize = if WIDTH >= usize::BITS {
+        usize::MAX
+    } else {
+        (1 << WIDTH) - 1
+    };
+
+    ///
+    /// # Description
+    ///
+    /// Instantiates a new [`PhysicalAddress`].
+    ///
+    /// # Parameters
+    ///
+    /// - `value`: The raw value of the address.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, the new address is returned. Upon failure, an error is returned instead.
+    ///
+    pub fn new(value: usize) -> Result<Self, Error> {
+        if value > Self::MAX_ADDR {
+            return Err(Error::new(ErrorCode::BadAddress, "physical address is too wide"));
+        }
+        Ok(Self(value))
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Instantiates a new [`PhysicalAddress`] without checking its width.
+    ///
+    /// # Parameters
+    ///
+    /// - `value`: The raw value of the address. It must fit in `WIDTH` bits.
+    ///
+    /// # Returns
+    ///
+    /// The new address.
+    ///
+    pub(crate) const fn new_unchecked(value: usize) -> Self {
+        Self(value)
+    }
+}
+
+impl<const WIDTH: u32> Address for PhysicalAddress<WIDTH> {
+    ///
+    /// # Description
+    ///
+    /// Instantiates a new [`PhysicalAddress`] from a raw value.
+    ///
+    /// # Parameters
+    ///
+    /// - `raw_addr`: The raw value.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Self)`: The new address.
+    /// - `Err(Error::BadAddress)`: If the provided address does not fit in `WIDTH` bits.
+    ///
+    fn from_raw_value(raw_addr: usize) -> Result<Self, Error> {
+        PhysicalAddress::new(raw_addr)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Aligns the target [`PhysicalAddress`] to the provided `alignment`. If the address is
+    /// already aligned, it is returned as is.
+    ///
+    /// # Parameters
+    ///
+    /// - `alignment`: The alignment to align the target address to.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, the aligned address is returned. Upon failure, an error is returned instead.
+    ///
+    fn align_up(&self, align: Alignment) -> Result<Self, Error> {
+        match self.0.checked_add(align as usize - 1) {
+            Some(value) => PhysicalAddress::new(mm::align_down(value, align)),
+            None => Err(Error::new(ErrorCode::BadAddress, "physical address is too wide")),
+        }
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Aligns the target [`PhysicalAddress`] down to the provided `alignment`. If the address is
+    /// already aligned, it is returned as is.
+    ///
+    /// # Parameters
+    ///
+    /// - `alignment`: The alignment to align the target address to.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, the aligned address is returned. Upon failure, an error is returned instead.
+    ///
+    fn align_down(&self, align: Alignment) -> Result<Self, Error> {
+        PhysicalAddress::new(mm::align_down(self.0, align))
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Checks if the target [`PhysicalAddress`] is aligned to the provided `alignment`.
+    ///
+    /// # Parameters
+    ///
+    /// - `alignment`: The alignment to check.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, `true` is returned if the address is aligned, otherwise `false`. Upon failure,
+    /// an error is returned instead.
+    ///
+    fn is_aligned(&self, align: Alignment) -> Result<bool, Error> {
+        Ok(mm::is_aligned(self.0, align))
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the maximum address for [`PhysicalAddress`].
+    ///
+    /// # Returns
+    ///
+    /// The maximum [`PhysicalAddress`].
+    ///
+    fn max_addr() -> usize {
+        Self::MAX_ADDR
+    }
+
+    fn into_raw_value(self) -> usize {
+        self.0
+    }
+
+    fn as_ptr(&self) -> *const u8 {
+        self.0 as *const u8
+    }
+
+    fn as_mut_ptr(&self) -> *mut u8 {
+        self.0 as *mut u8
+    }
+}
+
+impl<const WIDTH: u32> core::fmt::Debug for PhysicalAddress<WIDTH> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "{:#010x}", self.0)
+    }
+}
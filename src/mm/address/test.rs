@@ -0,0 +1,167 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::{
+    error::ErrorCode,
+    mm::{
+        Address,
+        Alignment,
+        IdentityMapping,
+        OffsetMapping,
+        PhysicalAddress,
+        Translate,
+        VirtualAddress,
+    },
+};
+
+//==================================================================================================
+// Unit Tests
+//==================================================================================================
+
+/// Width of physical addresses used in tests.
+const WIDTH: u32 = 20;
+
+/// Attempts to create physical addresses that do not fit in the address width.
+#[test]
+fn test_physical_address_width() {
+    if PhysicalAddress::<WIDTH>::max_addr() != 0xfffff {
+        panic!("unexpected maximum address (got={:#x})", PhysicalAddress::<WIDTH>::max_addr());
+    }
+    if PhysicalAddress::<{ usize::BITS }>::max_addr() != usize::MAX {
+        panic!("unexpected maximum address for full-width addresses");
+    }
+
+    let addr: PhysicalAddress<WIDTH> = match PhysicalAddress::from_raw_value(0xfff01) {
+        Ok(addr) => addr,
+        Err(e) => panic!("failed to create physical address (error={:?})", e),
+    };
+    match PhysicalAddress::<WIDTH>::from_raw_value(0x100000) {
+        Ok(addr) => panic!("created physical address that is too wide (addr={:?})", addr),
+        Err(e) if e.code == ErrorCode::BadAddress => {},
+        Err(e) => panic!("unexpected error code (error={:?})", e),
+    }
+
+    // Check if aligning past the address width fails.
+    match addr.align_up(Alignment::Align4096) {
+        Ok(addr) => panic!("aligned physical address past its width (addr={:?})", addr),
+        Err(e) if e.code == ErrorCode::BadAddress => {},
+        Err(e) => panic!("unexpected error code (error={:?})", e),
+    }
+    match addr.align_down(Alignment::Align4096) {
+        Ok(addr) if addr.into_raw_value() == 0xff000 => {},
+        Ok(addr) => panic!("unexpected aligned address (addr={:?})", addr),
+        Err(e) => panic!("failed to align physical address (error={:?})", e),
+    }
+}
+
+/// Attempts to translate addresses in identity-mapped and offset-mapped regions.
+#[test]
+fn test_translate() {
+    let base: PhysicalAddress<WIDTH> = match PhysicalAddress::new(0x1000) {
+        Ok(base) => base,
+        Err(e) => panic!("failed to create physical address (error={:?})", e),
+    };
+    let identity: IdentityMapping<WIDTH> = match IdentityMapping::new(base, 0x2000) {
+        Ok(mapping) => mapping,
+        Err(e) => panic!("failed to create identity mapping (error={:?})", e),
+    };
+    let offset: OffsetMapping<WIDTH> =
+        match OffsetMapping::new(base, VirtualAddress::new(0xc0001000), 0x2000) {
+            Ok(mapping) => mapping,
+            Err(e) => panic!("failed to create offset mapping (error={:?})", e),
+        };
+
+    // Translate an address in both directions.
+    let paddr: PhysicalAddress<WIDTH> = match PhysicalAddress::new(0x2abc) {
+        Ok(paddr) => paddr,
+        Err(e) => panic!("failed to create physical address (error={:?})", e),
+    };
+    match identity.to_virtual(paddr) {
+        Ok(vaddr) if vaddr == VirtualAddress::new(0x2abc) => {},
+        result => panic!("unexpected identity translation (result={:?})", result),
+    }
+    match offset.to_virtual(paddr) {
+        Ok(vaddr) if vaddr == VirtualAddress::new(0xc0002abc) => {},
+        result => panic!("unexpected offset translation (result={:?})", result),
+    }
+    match offset.to_physical(VirtualAddress::new(0xc0002abc)) {
+        Ok(addr) if addr == paddr => {},
+        result => panic!("unexpected reverse translation (result={:?})", result),
+    }
+
+    // Translate addresses that lie outside of the regions.
+    match identity.to_physical(VirtualAddress::new(0x3000)) {
+        Ok(addr) => panic!("translated address past the region (addr={:?})", addr),
+        Err(e) if e.code == ErrorCode::BadAddress => {},
+        Err(e) => panic!("unexpected error code (error={:?})", e),
+    }
+    match offset.to_physical(VirtualAddress::new(0x2abc)) {
+        Ok(addr) => panic!("translated address below the region (addr={:?})", addr),
+        Err(e) if e.code == ErrorCode::BadAddress => {},
+        Err(e) => panic!("unexpected error code (error={:?})", e),
+    }
+
+    // Create a region that does not fit in the address width.
+    match IdentityMapping::<WIDTH>::new(base, 0x100000) {
+        Ok(mapping) => panic!("created region past the address width (mapping={:?})", mapping),
+        Err(e) if e.code == ErrorCode::BadAddress => {},
+        Err(e) => panic!("unexpected error code (error={:?})", e),
+    }
+}
+
+/// Attempts to do arithmetic on virtual addresses near the bounds of the address space.
+#[test]
+fn test_virtual_address_arithmetic() {
+    let addr: VirtualAddress = VirtualAddress::new(0x1000);
+    if addr + 0x234 != VirtualAddress::new(0x1234) || addr - 0x1000 != VirtualAddress::new(0) {
+        panic!("unexpected results of operators");
+    }
+    match VirtualAddress::new(usize::MAX).checked_add(1) {
+        Ok(addr) => panic!("added past the address space (addr={:?})", addr),
+        Err(e) if e.code == ErrorCode::BadAddress => {},
+        Err(e) => panic!("unexpected error code (error={:?})", e),
+    }
+    match addr.checked_sub(0x1001) {
+        Ok(addr) => panic!("subtracted past the address space (addr={:?})", addr),
+        Err(e) if e.code == ErrorCode::BadAddress => {},
+        Err(e) => panic!("unexpected error code (error={:?})", e),
+    }
+
+    // Compute offsets between addresses.
+    match VirtualAddress::new(0x1234).offset_from(addr) {
+        Ok(0x234) => {},
+        result => panic!("unexpected offset (result={:?})", result),
+    }
+    match addr.offset_from(VirtualAddress::new(0x1234)) {
+        Ok(offset) => panic!("computed offset from a later address (offset={:#x})", offset),
+        Err(e) if e.code == ErrorCode::BadAddress => {},
+        Err(e) => panic!("unexpected error code (error={:?})", e),
+    }
+
+    // Align addresses in constant expressions.
+    const ALIGNED: VirtualAddress =
+        VirtualAddress::new(0x1001).align_up_const(Alignment::Align4096);
+    if ALIGNED != VirtualAddress::new(0x2000)
+        || ALIGNED.align_down_const(Alignment::Align8192) != VirtualAddress::new(0x2000)
+    {
+        panic!("unexpected aligned address (addr={:?})", ALIGNED);
+    }
+
+    // Convert addresses to and from pointers.
+    let value: u32 = 0;
+    let ptr: *const u32 = &value;
+    if VirtualAddress::from(ptr).into_ptr::<u32>() != ptr {
+        panic!("failed to convert address to and from pointers");
+    }
+}
+
+/// Attempts to add an offset that overflows a virtual address.
+#[test]
+#[should_panic]
+fn test_virtual_address_add_overflow() {
+    let _: VirtualAddress = VirtualAddress::new(usize::MAX) + 1;
+}
@@ -0,0 +1,239 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::{
+    error::{
+        Error,
+        ErrorCode,
+    },
+    mm::{
+        Address,
+        PhysicalAddress,
+        VirtualAddress,
+    },
+};
+
+//==================================================================================================
+// Structures
+//==================================================================================================
+
+///
+/// # Description
+///
+/// A region of physical memory that is mapped at the same virtual addresses.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IdentityMapping<const WIDTH: u32 = { usize::BITS }> {
+    /// Base address of the region.
+    base: PhysicalAddress<WIDTH>,
+    /// Size of the region (in bytes).
+    size: usize,
+}
+
+///
+/// # Description
+///
+/// A region of physical memory that is mapped at a fixed offset in the virtual address space.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OffsetMapping<const WIDTH: u32 = { usize::BITS }> {
+    /// Base physical address of the region.
+    phys_base: PhysicalAddress<WIDTH>,
+    /// Base virtual address of the region.
+    virt_base: VirtualAddress,
+    /// Size of the region (in bytes).
+    size: usize,
+}
+
+//==================================================================================================
+// Traits
+//==================================================================================================
+
+///
+/// # Description
+///
+/// A trait for regions of memory whose physical and virtual addresses can be translated into each
+/// other without walking page tables.
+///
+pub trait Translate<const WIDTH: u32 = { usize::BITS }> {
+    ///
+    /// # Description
+    ///
+    /// Translates a physical address into a virtual address.
+    ///
+    /// # Parameters
+    ///
+    /// - `addr`: The physical address to translate.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(VirtualAddress)`: The virtual address that maps `addr`.
+    /// - `Err(Error::BadAddress)`: If `addr` does not lie in the target region.
+    ///
+    fn to_virtual(&self, addr: PhysicalAddress<WIDTH>) -> Result<VirtualAddress, Error>;
+
+    ///
+    /// # Description
+    ///
+    /// Translates a virtual address into a physical address.
+    ///
+    /// # Parameters
+    ///
+    /// - `addr`: The virtual address to translate.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(PhysicalAddress)`: The physical address that is mapped at `addr`.
+    /// - `Err(Error::BadAddress)`: If `addr` does not lie in the target region.
+    ///
+    fn to_physical(&self, addr: VirtualAddress) -> Result<PhysicalAddress<WIDTH>, Error>;
+}
+
+//==================================================================================================
+// Implementations
+//==================================================================================================
+
+impl<const WIDTH: u32> IdentityMapping<WIDTH> {
+    ///
+    /// # Description
+    ///
+    /// Instantiates a new [`IdentityMapping`].
+    ///
+    /// # Parameters
+    ///
+    /// - `base`: Base address of the region.
+    /// - `size`: Size of the region (in bytes).
+    ///
+    /// # Returns
+    ///
+    /// Upon success, the new mapping is returned. Upon failure, an error is returned instead.
+    ///
+    pub fn new(base: PhysicalAddress<WIDTH>, size: usize) -> Result<Self, Error> {
+        check_region::<WIDTH>(base, VirtualAddress::new(base.into_raw_value()), size)?;
+        Ok(Self { base, size })
+    }
+}
+
+impl<const WIDTH: u32> Translate<WIDTH> for IdentityMapping<WIDTH> {
+    fn to_virtual(&self, addr: PhysicalAddress<WIDTH>) -> Result<VirtualAddress, Error> {
+        let offset: usize =
+            offset_of(self.base.into_raw_value(), addr.into_raw_value(), self.size)?;
+        VirtualAddress::new(self.base.into_raw_value()).checked_add(offset)
+    }
+
+    fn to_physical(&self, addr: VirtualAddress) -> Result<PhysicalAddress<WIDTH>, Error> {
+        let offset: usize =
+            offset_of(self.base.into_raw_value(), addr.into_raw_value(), self.size)?;
+        PhysicalAddress::new(self.base.into_raw_value() + offset)
+    }
+}
+
+impl<const WIDTH: u32> OffsetMapping<WIDTH> {
+    ///
+    /// # Description
+    ///
+    /// Instantiates a new [`OffsetMapping`].
+    ///
+    /// # Parameters
+    ///
+    /// - `phys_base`: Base physical address of the region.
+    /// - `virt_base`: Base virtual address of the region.
+    /// - `size`: Size of the region (in bytes).
+    ///
+    /// # Returns
+    ///
+    /// Upon success, the new mapping is returned. Upon failure, an error is returned instead.
+    ///
+    pub fn new(
+        phys_base: PhysicalAddress<WIDTH>,
+        virt_base: VirtualAddress,
+        size: usize,
+    ) -> Result<Self, Error> {
+        check_region::<WIDTH>(phys_base, virt_base, size)?;
+        Ok(Self {
+            phys_base,
+            virt_base,
+            size,
+        })
+    }
+}
+
+impl<const WIDTH: u32> Translate<WIDTH> for OffsetMapping<WIDTH> {
+    fn to_virtual(&self, addr: PhysicalAddress<WIDTH>) -> Result<VirtualAddress, Error> {
+        let offset: usize =
+            offset_of(self.phys_base.into_raw_value(), addr.into_raw_value(), self.size)?;
+        self.virt_base.checked_add(offset)
+    }
+
+    fn to_physical(&self, addr: VirtualAddress) -> Result<PhysicalAddress<WIDTH>, Error> {
+        let offset: usize =
+            offset_of(self.virt_base.into_raw_value(), addr.into_raw_value(), self.size)?;
+        PhysicalAddress::new(self.phys_base.into_raw_value() + offset)
+    }
+}
+
+//==================================================================================================
+// Standalone Functions
+//==================================================================================================
+
+///
+/// # Description
+///
+/// Checks if a region fits in both the physical and virtual address spaces.
+///
+/// # Parameters
+///
+/// - `phys_base`: Base physical address of the region.
+/// - `virt_base`: Base virtual address of the region.
+/// - `size`: Size of the region (in bytes).
+///
+/// # Returns
+///
+/// Upon success, `Ok(())` is returned. Upon failure, an error is returned instead.
+///
+fn check_region<const WIDTH: u32>(
+    phys_base: PhysicalAddress<WIDTH>,
+    virt_base: VirtualAddress,
+    size: usize,
+) -> Result<(), Error> {
+    // Check if the region is empty.
+    if size == 0 {
+        return Err(Error::new(ErrorCode::InvalidArgument, "empty region"));
+    }
+
+    // Check if the last byte of the region is addressable in both address spaces.
+    match (
+        phys_base.into_raw_value().checked_add(size - 1),
+        virt_base.into_raw_value().checked_add(size - 1),
+    ) {
+        (Some(phys_end), Some(_)) => PhysicalAddress::<WIDTH>::new(phys_end).map(|_| ()),
+        _ => Err(Error::new(ErrorCode::BadAddress, "region wraps around")),
+    }
+}
+
+///
+/// # Description
+///
+/// Computes the offset of an address within a region.
+///
+/// # Parameters
+///
+/// - `base`: Base address of the region.
+/// - `addr`: Target address.
+/// - `size`: Size of the region (in bytes).
+///
+/// # Returns
+///
+/// Upon success, the offset of `addr` within the region is returned. Upon failure, an error is
+/// returned instead.
+///
+fn offset_of(base: usize, addr: usize, size: usize) -> Result<usize, Error> {
+    match addr.checked_sub(base) {
+        Some(offset) if offset < size => Ok(offset),
+        _ => Err(Error::new(ErrorCode::BadAddress, "address is not mapped")),
+    }
+}
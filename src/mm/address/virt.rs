@@ -5,12 +5,21 @@
 // Imports
 //==================================================================================================
 
-use crate::mm::{
-    self,
-    Address,
-    Alignment,
+use crate::{
+    error::{
+        Error,
+        ErrorCode,
+    },
+    mm::{
+        self,
+        Address,
+        Alignment,
+    },
+};
+use ::core::ops::{
+    Add,
+    Sub,
 };
-use crate::error::Error;
 
 //==================================================================================================
 // Structures
@@ -32,6 +41,138 @@ impl VirtualAddress {
     pub const fn new(value: usize) -> Self {
         Self(value)
     }
+
+    ///
+    /// # Description
+    ///
+    /// Adds an offset to the target [`VirtualAddress`].
+    ///
+    /// # Parameters
+    ///
+    /// - `offset`: The offset to add.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, the resulting address is returned. Upon failure, an error is returned
+    /// instead.
+    ///
+    pub fn checked_add(&self, offset: usize) -> Result<Self, Error> {
+        match self.0.checked_add(offset) {
+            Some(value) => Ok(VirtualAddress::new(value)),
+            None => Err(Error::new(ErrorCode::BadAddress, "virtual address overflow")),
+        }
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Subtracts an offset from the target [`VirtualAddress`].
+    ///
+    /// # Parameters
+    ///
+    /// - `offset`: The offset to subtract.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, the resulting address is returned. Upon failure, an error is returned
+    /// instead.
+    ///
+    pub fn checked_sub(&self, offset: usize) -> Result<Self, Error> {
+        match self.0.checked_sub(offset) {
+            Some(value) => Ok(VirtualAddress::new(value)),
+            None => Err(Error::new(ErrorCode::BadAddress, "virtual address underflow")),
+        }
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Computes the distance from `origin` to the target [`VirtualAddress`].
+    ///
+    /// # Parameters
+    ///
+    /// - `origin`: The address to measure from.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, the number of bytes from `origin` to the target address is returned. Upon
+    /// failure, an error is returned instead.
+    ///
+    pub fn offset_from(&self, origin: VirtualAddress) -> Result<usize, Error> {
+        match self.0.checked_sub(origin.0) {
+            Some(offset) => Ok(offset),
+            None => Err(Error::new(ErrorCode::BadAddress, "origin lies after address")),
+        }
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Aligns the target [`VirtualAddress`] up to the provided `alignment`. Unlike
+    /// [`Address::align_up()`], this function may be used in constant expressions.
+    ///
+    /// # Parameters
+    ///
+    /// - `alignment`: The alignment to align the target address to.
+    ///
+    /// # Returns
+    ///
+    /// The aligned address.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the aligned address does not fit in a machine word.
+    ///
+    pub const fn align_up_const(self, align: Alignment) -> Self {
+        match self.0.checked_add(align as usize - 1) {
+            Some(value) => VirtualAddress::new(value & !(align as usize - 1)),
+            None => panic!("virtual address overflow"),
+        }
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Aligns the target [`VirtualAddress`] down to the provided `alignment`. Unlike
+    /// [`Address::align_down()`], this function may be used in constant expressions.
+    ///
+    /// # Parameters
+    ///
+    /// - `alignment`: The alignment to align the target address to.
+    ///
+    /// # Returns
+    ///
+    /// The aligned address.
+    ///
+    pub const fn align_down_const(self, align: Alignment) -> Self {
+        VirtualAddress::new(self.0 & !(align as usize - 1))
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Converts the target [`VirtualAddress`] into a pointer.
+    ///
+    /// # Returns
+    ///
+    /// A pointer to the target address.
+    ///
+    pub const fn into_ptr<T>(self) -> *const T {
+        self.0 as *const T
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Converts the target [`VirtualAddress`] into a mutable pointer.
+    ///
+    /// # Returns
+    ///
+    /// A mutable pointer to the target address.
+    ///
+    pub const fn into_mut_ptr<T>(self) -> *mut T {
+        self.0 as *mut T
+    }
 }
 
 impl Address for VirtualAddress {
@@ -137,3 +278,48 @@ impl core::fmt::Debug for VirtualAddress {
         write!(f, "{:#010x}", self.0)
     }
 }
+
+impl<T> From<*const T> for VirtualAddress {
+    fn from(ptr: *const T) -> Self {
+        VirtualAddress::new(ptr as usize)
+    }
+}
+
+impl<T> From<*mut T> for VirtualAddress {
+    fn from(ptr: *mut T) -> Self {
+        VirtualAddress::new(ptr as usize)
+    }
+}
+
+impl Add<usize> for VirtualAddress {
+    type Output = VirtualAddress;
+
+    fn add(self, offset: usize) -> Self::Output {
+        match self.checked_add(offset) {
+            Ok(addr) => addr,
+            Err(_) => panic!("virtual address overflow (addr={:?}, offset={:#x})", self, offset),
+        }
+    }
+}
+
+impl Sub<usize> for VirtualAddress {
+    type Output = VirtualAddress;
+
+    fn sub(self, offset: usize) -> Self::Output {
+        match self.checked_sub(offset) {
+            Ok(addr) => addr,
+            Err(_) => panic!("virtual address underflow (addr={:?}, offset={:#x})", self, offset),
+        }
+    }
+}
+
+impl Sub<VirtualAddress> for VirtualAddress {
+    type Output = usize;
+
+    fn sub(self, origin: VirtualAddress) -> Self::Output {
+        match self.offset_from(origin) {
+            Ok(offset) => offset,
+            Err(_) => panic!("virtual address underflow (addr={:?}, origin={:?})", self, origin),
+        }
+    }
+}
@@ -0,0 +1,486 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::{
+    config::memory_layout,
+    error::{
+        Error,
+        ErrorCode,
+    },
+    mm::{
+        AccessPermission,
+        Address,
+        Alignment,
+        MemoryAreaKind,
+        PageSize,
+        Region,
+        Size4KiB,
+        VirtualAddress,
+        VirtualMemoryArea,
+    },
+};
+use ::alloc::{
+    collections::{
+        btree_map,
+        BTreeMap,
+    },
+    vec::Vec,
+};
+
+//==================================================================================================
+// Structures
+//==================================================================================================
+
+///
+/// # Description
+///
+/// A map of the virtual memory areas of an address space.
+///
+/// Areas never overlap and are always page-aligned. Adjacent areas that have the same access
+/// permissions and purpose are merged, and areas are split whenever an operation covers only part
+/// of them.
+///
+#[derive(Debug)]
+pub struct AddressSpaceMap {
+    /// Areas, indexed by their start address.
+    areas: BTreeMap<VirtualAddress, VirtualMemoryArea>,
+    /// Range of addresses that areas may span.
+    bounds: Region<VirtualAddress>,
+}
+
+//==================================================================================================
+// Implementations
+//==================================================================================================
+
+impl AddressSpaceMap {
+    /// Alignment of areas.
+    const PAGE_ALIGNMENT: Alignment = <Size4KiB as PageSize>::ALIGNMENT;
+
+    ///
+    /// # Description
+    ///
+    /// Creates a new empty map of a user address space, which spans addresses from
+    /// [`memory_layout::USER_BASE`] to [`memory_layout::USER_END`].
+    ///
+    /// # Returns
+    ///
+    /// Upon success, a new empty map is returned. Upon failure, an error is returned instead.
+    ///
+    pub fn new() -> Result<Self, Error> {
+        Self::with_bounds(Region::new(
+            memory_layout::USER_BASE,
+            memory_layout::USER_END,
+            Self::PAGE_ALIGNMENT,
+        )?)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Creates a new empty map of an address space that spans a given range of addresses.
+    ///
+    /// # Parameters
+    ///
+    /// - `bounds`: Range of addresses that areas may span.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, a new empty map is returned. Upon failure, an error is returned instead.
+    ///
+    pub fn with_bounds(bounds: Region<VirtualAddress>) -> Result<Self, Error> {
+        Ok(Self {
+            areas: BTreeMap::new(),
+            bounds: Region::new(bounds.start(), bounds.end(), Self::PAGE_ALIGNMENT)?,
+        })
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the range of addresses that areas may span.
+    ///
+    /// # Returns
+    ///
+    /// The range of addresses that areas may span.
+    ///
+    pub fn bounds(&self) -> &Region<VirtualAddress> {
+        &self.bounds
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the number of areas in the map.
+    ///
+    /// # Returns
+    ///
+    /// The number of areas in the map.
+    ///
+    pub fn len(&self) -> usize {
+        self.areas.len()
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Checks if the map is empty.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the map has no areas, `false` otherwise.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.areas.is_empty()
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns an iterator over the areas of the map, in increasing order of addresses.
+    ///
+    /// # Returns
+    ///
+    /// An iterator over the areas of the map.
+    ///
+    pub fn iter(&self) -> btree_map::Values<'_, VirtualAddress, VirtualMemoryArea> {
+        self.areas.values()
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Looks up the area that contains an address.
+    ///
+    /// # Parameters
+    ///
+    /// - `addr`: Target address.
+    ///
+    /// # Returns
+    ///
+    /// The area that contains `addr`, if any.
+    ///
+    pub fn lookup(&self, addr: VirtualAddress) -> Option<&VirtualMemoryArea> {
+        match self.areas.range(..=addr).next_back() {
+            Some((_, area)) if area.region.contains(&addr) => Some(area),
+            _ => None,
+        }
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Inserts an area in the map.
+    ///
+    /// # Parameters
+    ///
+    /// - `region`: Range of the area.
+    /// - `permission`: Access permissions of the area.
+    /// - `kind`: Purpose of the area.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, `Ok(())` is returned. Upon failure, an error is returned instead.
+    ///
+    pub fn insert(
+        &mut self,
+        region: Region<VirtualAddress>,
+        permission: AccessPermission,
+        kind: MemoryAreaKind,
+    ) -> Result<(), Error> {
+        let region: Region<VirtualAddress> = self.check_region(&region)?;
+
+        // Check if the region overlaps with an existing area.
+        if self.lookup(region.start()).is_some()
+            || self
+                .areas
+                .range(region.start()..region.end())
+                .next()
+                .is_some()
+        {
+            return Err(Error::new(ErrorCode::EntryExists, "region is already mapped"));
+        }
+
+        let (start, end): (VirtualAddress, VirtualAddress) = (region.start(), region.end());
+        self.areas.insert(
+            start,
+            VirtualMemoryArea {
+                region,
+                permission,
+                kind,
+            },
+        );
+
+        self.coalesce(start, end)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Removes a range of addresses from the map. Areas that are partially covered by the range
+    /// are split, and parts of the range that are not mapped are ignored.
+    ///
+    /// # Parameters
+    ///
+    /// - `region`: Range of addresses to remove.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, `Ok(())` is returned. Upon failure, an error is returned instead.
+    ///
+    pub fn remove(&mut self, region: &Region<VirtualAddress>) -> Result<(), Error> {
+        let region: Region<VirtualAddress> = self.check_region(region)?;
+
+        self.split(region.start())?;
+        self.split(region.end())?;
+
+        let keys: Vec<VirtualAddress> = self
+            .areas
+            .range(region.start()..region.end())
+            .map(|(start, _)| *start)
+            .collect();
+        for start in keys {
+            self.areas.remove(&start);
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Changes the access permissions of a range of addresses. Areas that are partially covered by
+    /// the range are split, and areas that end up with the same access permissions as their
+    /// neighbours are merged.
+    ///
+    /// # Parameters
+    ///
+    /// - `region`: Range of addresses to change. It must be fully mapped.
+    /// - `permission`: New access permissions.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, `Ok(())` is returned. Upon failure, an error is returned instead.
+    ///
+    pub fn protect(
+        &mut self,
+        region: &Region<VirtualAddress>,
+        permission: AccessPermission,
+    ) -> Result<(), Error> {
+        let region: Region<VirtualAddress> = self.check_region(region)?;
+
+        // Check if the region is fully mapped.
+        let mut cursor: VirtualAddress = region.start();
+        while cursor < region.end() {
+            match self.lookup(cursor) {
+                Some(area) => cursor = area.region.end(),
+                None => return Err(Error::new(ErrorCode::BadAddress, "region is not mapped")),
+            }
+        }
+
+        self.split(region.start())?;
+        self.split(region.end())?;
+
+        for (_, area) in self.areas.range_mut(region.start()..region.end()) {
+            area.permission = permission;
+        }
+
+        self.coalesce(region.start(), region.end())
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Finds the lowest range of addresses that is not mapped and is large enough.
+    ///
+    /// # Parameters
+    ///
+    /// - `len`: Length of the range (in bytes). It must be a multiple of the page size.
+    /// - `align`: Alignment of the start address of the range.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, the range that was found is returned. Upon failure, an error is returned
+    /// instead.
+    ///
+    pub fn find_free_gap(
+        &self,
+        len: usize,
+        align: Alignment,
+    ) -> Result<Region<VirtualAddress>, Error> {
+        // Check if length is invalid.
+        if len == 0 || len % Size4KiB::SIZE != 0 {
+            return Err(Error::new(ErrorCode::InvalidArgument, "invalid length"));
+        }
+
+        let align: Alignment = align.max(Self::PAGE_ALIGNMENT);
+        let mut cursor: VirtualAddress = self.bounds.start();
+        for area in self.areas.values() {
+            if let Some(region) = fit(cursor, area.region.start(), len, align) {
+                return Ok(region);
+            }
+            cursor = cursor.max(area.region.end());
+        }
+
+        match fit(cursor, self.bounds.end(), len, align) {
+            Some(region) => Ok(region),
+            None => Err(Error::new(ErrorCode::OutOfMemory, "no free gap")),
+        }
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Checks if a region may be mapped in the map.
+    ///
+    /// # Parameters
+    ///
+    /// - `region`: Target region.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, the target region, aligned to the page size, is returned. Upon failure, an
+    /// error is returned instead.
+    ///
+    fn check_region(
+        &self,
+        region: &Region<VirtualAddress>,
+    ) -> Result<Region<VirtualAddress>, Error> {
+        // Check if the region is empty.
+        if region.is_empty() {
+            return Err(Error::new(ErrorCode::InvalidArgument, "empty region"));
+        }
+
+        // Check if the region lies outside of the address space.
+        if region.start() < self.bounds.start() || region.end() > self.bounds.end() {
+            return Err(Error::new(ErrorCode::BadAddress, "region is out of bounds"));
+        }
+
+        Region::new(region.start(), region.end(), Self::PAGE_ALIGNMENT)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Splits the area that contains an address, so that an area starts at that address.
+    ///
+    /// # Parameters
+    ///
+    /// - `addr`: Target address.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, `Ok(())` is returned. Upon failure, an error is returned instead.
+    ///
+    fn split(&mut self, addr: VirtualAddress) -> Result<(), Error> {
+        let area: &mut VirtualMemoryArea = match self.areas.range_mut(..addr).next_back() {
+            Some((_, area)) if area.region.contains(&addr) => area,
+            _ => return Ok(()),
+        };
+
+        let (lower, upper): (Region<VirtualAddress>, Region<VirtualAddress>) =
+            area.region.split_at(addr)?;
+        area.region = lower;
+        let upper: VirtualMemoryArea = VirtualMemoryArea {
+            region: upper,
+            permission: area.permission,
+            kind: area.kind,
+        };
+        self.areas.insert(addr, upper);
+
+        Ok(())
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Merges areas that lie in a range of addresses, or that are adjacent to it, whenever they
+    /// have the same access permissions and purpose.
+    ///
+    /// # Parameters
+    ///
+    /// - `start`: Start address of the range.
+    /// - `end`: End address of the range.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, `Ok(())` is returned. Upon failure, an error is returned instead.
+    ///
+    fn coalesce(&mut self, start: VirtualAddress, end: VirtualAddress) -> Result<(), Error> {
+        // Start at the area that precedes the range, if any.
+        let first: VirtualAddress = match self.areas.range(..start).next_back() {
+            Some((first, _)) => *first,
+            None => start,
+        };
+        let keys: Vec<VirtualAddress> = self
+            .areas
+            .range(first..=end)
+            .map(|(start, _)| *start)
+            .collect();
+
+        let mut current: Option<VirtualAddress> = None;
+        for key in keys {
+            match current {
+                Some(prev) if self.areas[&prev].can_merge(&self.areas[&key]) => {
+                    if let (Some(next), Some(area)) =
+                        (self.areas.remove(&key), self.areas.get_mut(&prev))
+                    {
+                        area.region = Region::new(
+                            area.region.start(),
+                            next.region.end(),
+                            Self::PAGE_ALIGNMENT,
+                        )?;
+                    }
+                },
+                _ => current = Some(key),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+//==================================================================================================
+// Standalone Functions
+//==================================================================================================
+
+///
+/// # Description
+///
+/// Attempts to fit a range of addresses in a gap.
+///
+/// # Parameters
+///
+/// - `start`: Start address of the gap.
+/// - `end`: End address of the gap.
+/// - `len`: Length of the range (in bytes).
+/// - `align`: Alignment of the start address of the range.
+///
+/// # Returns
+///
+/// The range of addresses, if it fits in the gap.
+///
+fn fit(
+    start: VirtualAddress,
+    end: VirtualAddress,
+    len: usize,
+    align: Alignment,
+) -> Option<Region<VirtualAddress>> {
+    let start: usize = start
+        .into_raw_value()
+        .checked_next_multiple_of(align as usize)?;
+    let end_of_range: usize = start.checked_add(len)?;
+    if end_of_range > end.into_raw_value() {
+        return None;
+    }
+
+    Region::new(
+        VirtualAddress::new(start),
+        VirtualAddress::new(end_of_range),
+        AddressSpaceMap::PAGE_ALIGNMENT,
+    )
+    .ok()
+}
@@ -0,0 +1,114 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::mm::{
+    AccessPermission,
+    Region,
+    VirtualAddress,
+};
+
+//==================================================================================================
+// Structures
+//==================================================================================================
+
+///
+/// # Description
+///
+/// A type that represents the purpose of a [`VirtualMemoryArea`].
+///
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum MemoryAreaKind {
+    /// Executable code and static data.
+    Code,
+    /// Dynamically allocated data.
+    Heap,
+    /// Stack of a thread.
+    Stack,
+    /// Memory-mapped I/O.
+    Mmio,
+}
+
+///
+/// # Description
+///
+/// A contiguous range of virtual memory that is mapped with the same access permissions and for
+/// the same purpose.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VirtualMemoryArea {
+    /// Range of the area.
+    pub(super) region: Region<VirtualAddress>,
+    /// Access permissions of the area.
+    pub(super) permission: AccessPermission,
+    /// Purpose of the area.
+    pub(super) kind: MemoryAreaKind,
+}
+
+//==================================================================================================
+// Implementations
+//==================================================================================================
+
+impl VirtualMemoryArea {
+    ///
+    /// # Description
+    ///
+    /// Returns the range of the target area.
+    ///
+    /// # Returns
+    ///
+    /// The range of the target area.
+    ///
+    pub fn region(&self) -> &Region<VirtualAddress> {
+        &self.region
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the access permissions of the target area.
+    ///
+    /// # Returns
+    ///
+    /// The access permissions of the target area.
+    ///
+    pub fn permission(&self) -> AccessPermission {
+        self.permission
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the purpose of the target area.
+    ///
+    /// # Returns
+    ///
+    /// The purpose of the target area.
+    ///
+    pub fn kind(&self) -> MemoryAreaKind {
+        self.kind
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Checks if the target area can be merged with an area that follows it.
+    ///
+    /// # Parameters
+    ///
+    /// - `next`: The area that follows the target area.
+    ///
+    /// # Returns
+    ///
+    /// `true` if both areas are adjacent and have the same access permissions and purpose,
+    /// `false` otherwise.
+    ///
+    pub(super) fn can_merge(&self, next: &VirtualMemoryArea) -> bool {
+        self.region.end() == next.region.start()
+            && self.permission == next.permission
+            && self.kind == next.kind
+    }
+}
@@ -0,0 +1,186 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::{
+    config::memory_layout,
+    error::ErrorCode,
+    mm::{
+        AccessPermission,
+        Address,
+        AddressSpaceMap,
+        Alignment,
+        MemoryAreaKind,
+        Region,
+        VirtualAddress,
+        VirtualMemoryArea,
+    },
+};
+use ::alloc::vec::Vec;
+
+//==================================================================================================
+// Unit Tests
+//==================================================================================================
+
+/// Size of pages used in tests.
+const PAGE_SIZE: usize = 4096;
+
+/// Helper test function that creates a region of user space, given in page offsets from its base.
+fn test_helper_region(first: usize, last: usize) -> Region<VirtualAddress> {
+    let base: usize = memory_layout::USER_BASE.into_raw_value();
+    match Region::new(
+        VirtualAddress::new(base + first * PAGE_SIZE),
+        VirtualAddress::new(base + last * PAGE_SIZE),
+        Alignment::Align4096,
+    ) {
+        Ok(region) => region,
+        Err(e) => panic!("failed to create region (error={:?})", e),
+    }
+}
+
+/// Helper test function that creates an [`AddressSpaceMap`] for user space.
+fn test_helper_create_map() -> AddressSpaceMap {
+    match AddressSpaceMap::new() {
+        Ok(map) => map,
+        Err(e) => panic!("failed to create address space map (error={:?})", e),
+    }
+}
+
+/// Helper test function that collects areas of an [`AddressSpaceMap`] as ranges of page offsets.
+fn test_helper_collect(map: &AddressSpaceMap) -> Vec<(usize, usize)> {
+    let base: usize = memory_layout::USER_BASE.into_raw_value();
+    map.iter()
+        .map(|area: &VirtualMemoryArea| {
+            (
+                (area.region().start().into_raw_value() - base) / PAGE_SIZE,
+                (area.region().end().into_raw_value() - base) / PAGE_SIZE,
+            )
+        })
+        .collect()
+}
+
+/// Attempts to insert, look up and remove areas.
+#[test]
+fn test_insert_lookup_remove() {
+    let mut map: AddressSpaceMap = test_helper_create_map();
+
+    // Insert adjacent areas, which should be merged only if they have the same attributes.
+    for (first, last, kind) in [
+        (0, 2, MemoryAreaKind::Code),
+        (2, 4, MemoryAreaKind::Code),
+        (4, 6, MemoryAreaKind::Heap),
+    ] {
+        if let Err(e) = map.insert(test_helper_region(first, last), AccessPermission::RDWR, kind) {
+            panic!("failed to insert area (error={:?})", e);
+        }
+    }
+    if test_helper_collect(&map) != [(0, 4), (4, 6)] {
+        panic!("unexpected areas (got={:?})", test_helper_collect(&map));
+    }
+
+    // Insert an overlapping area.
+    match map.insert(test_helper_region(5, 8), AccessPermission::RDONLY, MemoryAreaKind::Mmio) {
+        Ok(_) => panic!("inserted overlapping area"),
+        Err(e) if e.code == ErrorCode::EntryExists => {},
+        Err(e) => panic!("unexpected error code (error={:?})", e),
+    }
+
+    // Look up addresses.
+    match map.lookup(test_helper_region(5, 6).start()) {
+        Some(area) if area.kind() == MemoryAreaKind::Heap => {},
+        result => panic!("unexpected area (result={:?})", result),
+    }
+    if map.lookup(test_helper_region(6, 7).start()).is_some() {
+        panic!("found area past the end of the map");
+    }
+
+    // Remove a range in the middle of an area.
+    if let Err(e) = map.remove(&test_helper_region(1, 2)) {
+        panic!("failed to remove range (error={:?})", e);
+    }
+    if test_helper_collect(&map) != [(0, 1), (2, 4), (4, 6)] {
+        panic!("unexpected areas (got={:?})", test_helper_collect(&map));
+    }
+}
+
+/// Attempts to change access permissions of ranges that span multiple areas.
+#[test]
+fn test_protect() {
+    let mut map: AddressSpaceMap = test_helper_create_map();
+    if let Err(e) =
+        map.insert(test_helper_region(0, 8), AccessPermission::RDWR, MemoryAreaKind::Heap)
+    {
+        panic!("failed to insert area (error={:?})", e);
+    }
+
+    // Protect a range in the middle of an area, which should split it.
+    if let Err(e) = map.protect(&test_helper_region(2, 4), AccessPermission::RDONLY) {
+        panic!("failed to protect range (error={:?})", e);
+    }
+    if test_helper_collect(&map) != [(0, 2), (2, 4), (4, 8)] {
+        panic!("unexpected areas (got={:?})", test_helper_collect(&map));
+    }
+    match map.lookup(test_helper_region(3, 4).start()) {
+        Some(area) if area.permission() == AccessPermission::RDONLY => {},
+        result => panic!("unexpected area (result={:?})", result),
+    }
+
+    // Restore access permissions, which should merge areas back.
+    if let Err(e) = map.protect(&test_helper_region(1, 5), AccessPermission::RDWR) {
+        panic!("failed to protect range (error={:?})", e);
+    }
+    if test_helper_collect(&map) != [(0, 8)] {
+        panic!("unexpected areas (got={:?})", test_helper_collect(&map));
+    }
+
+    // Protect a range that is not fully mapped.
+    match map.protect(&test_helper_region(6, 10), AccessPermission::RDONLY) {
+        Ok(_) => panic!("protected range that is not mapped"),
+        Err(e) if e.code == ErrorCode::BadAddress => {},
+        Err(e) => panic!("unexpected error code (error={:?})", e),
+    }
+}
+
+/// Attempts to find free gaps between areas.
+#[test]
+fn test_find_free_gap() {
+    let mut map: AddressSpaceMap = test_helper_create_map();
+    for (first, last) in [(0, 1), (2, 4), (5, 6)] {
+        if let Err(e) = map.insert(
+            test_helper_region(first, last),
+            AccessPermission::RDWR,
+            MemoryAreaKind::Stack,
+        ) {
+            panic!("failed to insert area (error={:?})", e);
+        }
+    }
+
+    match map.find_free_gap(PAGE_SIZE, Alignment::Align4096) {
+        Ok(region) if region == test_helper_region(1, 2) => {},
+        result => panic!("unexpected gap (result={:?})", result),
+    }
+    match map.find_free_gap(2 * PAGE_SIZE, Alignment::Align4096) {
+        Ok(region) if region == test_helper_region(6, 8) => {},
+        result => panic!("unexpected gap (result={:?})", result),
+    }
+    match map.find_free_gap(PAGE_SIZE, Alignment::Align16384) {
+        Ok(region) if region == test_helper_region(4, 5) => {},
+        result => panic!("unexpected gap (result={:?})", result),
+    }
+
+    // Find gaps of invalid or excessive length.
+    match map.find_free_gap(PAGE_SIZE + 1, Alignment::Align4096) {
+        Ok(region) => panic!("found gap of unaligned length (region={:?})", region),
+        Err(e) if e.code == ErrorCode::InvalidArgument => {},
+        Err(e) => panic!("unexpected error code (error={:?})", e),
+    }
+    let len: usize = map.bounds().len();
+    match map.find_free_gap(len, Alignment::Align4096) {
+        Ok(region) => panic!("found gap larger than free space (region={:?})", region),
+        Err(e) if e.code == ErrorCode::OutOfMemory => {},
+        Err(e) => panic!("unexpected error code (error={:?})", e),
+    }
+}
@@ -0,0 +1,477 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::{
+    error::{
+        Error,
+        ErrorCode,
+    },
+    mm::AccessPermission,
+};
+
+//==================================================================================================
+// Structures
+//==================================================================================================
+
+///
+/// # Description
+///
+/// A type that represents the privilege level that is required to access a mapping.
+///
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PrivilegeLevel {
+    /// Access from supervisor mode only.
+    Supervisor,
+    /// Access from user and supervisor modes.
+    User,
+}
+
+///
+/// # Description
+///
+/// A type that represents the caching policy of a mapping.
+///
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum CachePolicy {
+    /// Cached memory with write-back semantics.
+    WriteBack,
+    /// Uncached memory, suitable for device registers.
+    Uncached,
+    /// Uncached memory that combines writes, suitable for frame buffers.
+    WriteCombining,
+}
+
+///
+/// # Description
+///
+/// A type that represents how a mapping is shared among address spaces.
+///
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SharingPolicy {
+    /// Private to a single address space.
+    Private,
+    /// Shared until written to, then copied into a private mapping.
+    CopyOnWrite,
+    /// Shared among address spaces.
+    Shared,
+}
+
+///
+/// # Description
+///
+/// A type that represents the flags of a memory mapping: access permissions, privilege level,
+/// caching policy and sharing policy.
+///
+/// Flags are validated upon construction. A mapping may not be writable and executable at the
+/// same time (W^X), device memory may not be executed nor copied on write, and copy-on-write
+/// mappings must be writable.
+///
+/// # Encoding
+///
+/// Flags are encoded in the kernel-call ABI as follows. Bits that are not listed are reserved and
+/// must be zero.
+///
+/// | Bits | Field                                                              |
+/// |------|--------------------------------------------------------------------|
+/// | 0-2  | Access permissions, as encoded by [`AccessPermission`].            |
+/// | 3    | Privilege level: 0 = supervisor, 1 = user.                         |
+/// | 4-5  | Caching policy: 0 = write-back, 1 = uncached, 2 = write-combining. |
+/// | 6-7  | Sharing policy: 0 = private, 1 = copy-on-write, 2 = shared.        |
+///
+#[derive(Default, Copy, Clone, PartialEq, Eq)]
+pub struct MappingFlags {
+    /// Access permissions.
+    permission: AccessPermission,
+    /// Privilege level.
+    privilege: PrivilegeLevel,
+    /// Caching policy.
+    cache: CachePolicy,
+    /// Sharing policy.
+    sharing: SharingPolicy,
+}
+
+impl From<MappingFlags> for u32 {
+    ///
+    /// # Description
+    ///
+    /// Converts a [`MappingFlags`] to a [u32].
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - Value.
+    ///
+    /// # Returns
+    ///
+    /// Returns the [u32] representation of the [`MappingFlags`].
+    ///
+    fn from(value: MappingFlags) -> Self {
+        let privilege: u32 = match value.privilege {
+            PrivilegeLevel::Supervisor => 0,
+            PrivilegeLevel::User => 1,
+        };
+        let cache: u32 = match value.cache {
+            CachePolicy::WriteBack => 0,
+            CachePolicy::Uncached => 1,
+            CachePolicy::WriteCombining => 2,
+        };
+        let sharing: u32 = match value.sharing {
+            SharingPolicy::Private => 0,
+            SharingPolicy::CopyOnWrite => 1,
+            SharingPolicy::Shared => 2,
+        };
+
+        u32::from(value.permission)
+            | (privilege << MappingFlags::PRIVILEGE_SHIFT)
+            | (cache << MappingFlags::CACHE_SHIFT)
+            | (sharing << MappingFlags::SHARING_SHIFT)
+    }
+}
+
+impl TryFrom<u32> for MappingFlags {
+    type Error = Error;
+
+    ///
+    /// # Description
+    ///
+    /// Attempts to constructs a [`MappingFlags`] from a [u32].
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - Value.
+    ///
+    /// # Returns
+    ///
+    /// Upon successful completion, a [`MappingFlags`] is returned. Upon failure, an error is
+    /// returned instead.
+    ///
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        if value & !Self::VALID_MASK != 0 {
+            return Err(Error::new(ErrorCode::InvalidArgument, "invalid value for mapping flags"));
+        }
+
+        let permission: AccessPermission =
+            AccessPermission::try_from(value & Self::PERMISSION_MASK)?;
+        let privilege: PrivilegeLevel = match (value >> Self::PRIVILEGE_SHIFT) & 0b1 {
+            0 => PrivilegeLevel::Supervisor,
+            _ => PrivilegeLevel::User,
+        };
+        let cache: CachePolicy = match (value >> Self::CACHE_SHIFT) & 0b11 {
+            0 => CachePolicy::WriteBack,
+            1 => CachePolicy::Uncached,
+            2 => CachePolicy::WriteCombining,
+            _ => return Err(Error::new(ErrorCode::InvalidArgument, "invalid caching policy")),
+        };
+        let sharing: SharingPolicy = match (value >> Self::SHARING_SHIFT) & 0b11 {
+            0 => SharingPolicy::Private,
+            1 => SharingPolicy::CopyOnWrite,
+            2 => SharingPolicy::Shared,
+            _ => return Err(Error::new(ErrorCode::InvalidArgument, "invalid sharing policy")),
+        };
+
+        Self::new(permission, privilege, cache, sharing)
+    }
+}
+
+impl From<MappingFlags> for usize {
+    ///
+    /// # Description
+    ///
+    /// Converts a [`MappingFlags`] to a [usize].
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - Value.
+    ///
+    /// # Returns
+    ///
+    /// Returns the [usize] representation of the [`MappingFlags`].
+    ///
+    fn from(value: MappingFlags) -> Self {
+        u32::from(value) as usize
+    }
+}
+
+impl TryFrom<usize> for MappingFlags {
+    type Error = Error;
+
+    ///
+    /// # Description
+    ///
+    /// Attempts to constructs a [`MappingFlags`] from a [usize].
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - Value.
+    ///
+    /// # Returns
+    ///
+    /// Upon successful completion, a [`MappingFlags`] is returned. Upon failure, an error is
+    /// returned instead.
+    ///
+    fn try_from(value: usize) -> Result<Self, Self::Error> {
+        match u32::try_from(value) {
+            Ok(value) => Self::try_from(value),
+            Err(_) => {
+                Err(Error::new(ErrorCode::InvalidArgument, "invalid value for mapping flags"))
+            },
+        }
+    }
+}
+
+//==================================================================================================
+// Implementations
+//==================================================================================================
+
+impl Default for PrivilegeLevel {
+    ///
+    /// # Description
+    ///
+    /// Constructs a [`PrivilegeLevel`] with default values.
+    ///
+    /// # Returns
+    ///
+    /// Returns a [`PrivilegeLevel`] with [`PrivilegeLevel::Supervisor`].
+    ///
+    fn default() -> Self {
+        Self::Supervisor
+    }
+}
+
+impl Default for CachePolicy {
+    ///
+    /// # Description
+    ///
+    /// Constructs a [`CachePolicy`] with default values.
+    ///
+    /// # Returns
+    ///
+    /// Returns a [`CachePolicy`] with [`CachePolicy::WriteBack`].
+    ///
+    fn default() -> Self {
+        Self::WriteBack
+    }
+}
+
+impl Default for SharingPolicy {
+    ///
+    /// # Description
+    ///
+    /// Constructs a [`SharingPolicy`] with default values.
+    ///
+    /// # Returns
+    ///
+    /// Returns a [`SharingPolicy`] with [`SharingPolicy::Private`].
+    ///
+    fn default() -> Self {
+        Self::Private
+    }
+}
+
+impl MappingFlags {
+    /// Mask of the access permissions.
+    const PERMISSION_MASK: u32 = 0b111;
+    /// Shift of the privilege level.
+    const PRIVILEGE_SHIFT: u32 = 3;
+    /// Shift of the caching policy.
+    const CACHE_SHIFT: u32 = 4;
+    /// Shift of the sharing policy.
+    const SHARING_SHIFT: u32 = 6;
+    /// Mask of all bits that are not reserved.
+    const VALID_MASK: u32 = 0xff;
+
+    ///
+    /// # Description
+    ///
+    /// Constructs a [`MappingFlags`] with the given attributes.
+    ///
+    /// # Parameters
+    ///
+    /// * `permission` - Access permissions.
+    /// * `privilege` - Privilege level.
+    /// * `cache` - Caching policy.
+    /// * `sharing` - Sharing policy.
+    ///
+    /// # Returns
+    ///
+    /// Upon successful completion, a [`MappingFlags`] is returned. Upon failure, an error is
+    /// returned instead.
+    ///
+    pub fn new(
+        permission: AccessPermission,
+        privilege: PrivilegeLevel,
+        cache: CachePolicy,
+        sharing: SharingPolicy,
+    ) -> Result<Self, Error> {
+        // Check if the mapping is both writable and executable.
+        if permission.is_writable() && permission.is_executable() {
+            return Err(Error::new(
+                ErrorCode::InvalidArgument,
+                "mapping cannot be both writable and executable",
+            ));
+        }
+
+        // Check if device memory is executable or copied on write.
+        if cache != CachePolicy::WriteBack {
+            if permission.is_executable() {
+                return Err(Error::new(
+                    ErrorCode::InvalidArgument,
+                    "uncached mapping cannot be executable",
+                ));
+            }
+            if sharing == SharingPolicy::CopyOnWrite {
+                return Err(Error::new(
+                    ErrorCode::InvalidArgument,
+                    "uncached mapping cannot be copied on write",
+                ));
+            }
+        }
+
+        // Check if a copy-on-write mapping cannot be written to.
+        if sharing == SharingPolicy::CopyOnWrite && !permission.is_writable() {
+            return Err(Error::new(
+                ErrorCode::InvalidArgument,
+                "copy-on-write mapping must be writable",
+            ));
+        }
+
+        Ok(Self {
+            permission,
+            privilege,
+            cache,
+            sharing,
+        })
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Constructs a [`MappingFlags`] for private, cached memory that is accessible from user mode.
+    ///
+    /// # Parameters
+    ///
+    /// * `permission` - Access permissions.
+    ///
+    /// # Returns
+    ///
+    /// Upon successful completion, a [`MappingFlags`] is returned. Upon failure, an error is
+    /// returned instead.
+    ///
+    pub fn user(permission: AccessPermission) -> Result<Self, Error> {
+        Self::new(permission, PrivilegeLevel::User, CachePolicy::WriteBack, SharingPolicy::Private)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Constructs a [`MappingFlags`] for memory-mapped I/O that is accessible from user mode.
+    ///
+    /// # Parameters
+    ///
+    /// * `permission` - Access permissions.
+    /// * `cache` - Caching policy.
+    ///
+    /// # Returns
+    ///
+    /// Upon successful completion, a [`MappingFlags`] is returned. Upon failure, an error is
+    /// returned instead.
+    ///
+    pub fn mmio(permission: AccessPermission, cache: CachePolicy) -> Result<Self, Error> {
+        if cache == CachePolicy::WriteBack {
+            return Err(Error::new(ErrorCode::InvalidArgument, "mmio mapping must be uncached"));
+        }
+        Self::new(permission, PrivilegeLevel::User, cache, SharingPolicy::Private)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the access permissions of the target mapping flags.
+    ///
+    /// # Returns
+    ///
+    /// Returns the access permissions of the target mapping flags.
+    ///
+    pub fn permission(&self) -> AccessPermission {
+        self.permission
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the privilege level of the target mapping flags.
+    ///
+    /// # Returns
+    ///
+    /// Returns the privilege level of the target mapping flags.
+    ///
+    pub fn privilege(&self) -> PrivilegeLevel {
+        self.privilege
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the caching policy of the target mapping flags.
+    ///
+    /// # Returns
+    ///
+    /// Returns the caching policy of the target mapping flags.
+    ///
+    pub fn cache(&self) -> CachePolicy {
+        self.cache
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the sharing policy of the target mapping flags.
+    ///
+    /// # Returns
+    ///
+    /// Returns the sharing policy of the target mapping flags.
+    ///
+    pub fn sharing(&self) -> SharingPolicy {
+        self.sharing
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Asserts if the mapping is accessible from user mode.
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the mapping is accessible from user mode, `false` otherwise.
+    ///
+    pub fn is_user(&self) -> bool {
+        self.privilege == PrivilegeLevel::User
+    }
+}
+
+impl core::fmt::Debug for MappingFlags {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{:?}{} {} {}",
+            self.permission,
+            match self.privilege {
+                PrivilegeLevel::Supervisor => "s",
+                PrivilegeLevel::User => "u",
+            },
+            match self.cache {
+                CachePolicy::WriteBack => "wb",
+                CachePolicy::Uncached => "uc",
+                CachePolicy::WriteCombining => "wc",
+            },
+            match self.sharing {
+                SharingPolicy::Private => "private",
+                SharingPolicy::CopyOnWrite => "cow",
+                SharingPolicy::Shared => "shared",
+            }
+        )
+    }
+}
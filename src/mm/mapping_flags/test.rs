@@ -0,0 +1,93 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::{
+    error::ErrorCode,
+    mm::{
+        AccessPermission,
+        CachePolicy,
+        MappingFlags,
+        PrivilegeLevel,
+        SharingPolicy,
+    },
+};
+
+//==================================================================================================
+// Unit Tests
+//==================================================================================================
+
+/// Attempts to encode and decode all valid mapping flags.
+#[test]
+fn test_mapping_flags_round_trip() {
+    let mut valid: usize = 0;
+    for raw in 0..=0xff_u32 {
+        let flags: MappingFlags = match MappingFlags::try_from(raw) {
+            Ok(flags) => flags,
+            Err(e) if e.code == ErrorCode::InvalidArgument => continue,
+            Err(e) => panic!("unexpected error code (raw={:#x}, error={:?})", raw, e),
+        };
+        valid += 1;
+
+        if u32::from(flags) != raw {
+            panic!("mapping flags did not round-trip (raw={:#x}, flags={:?})", raw, flags);
+        }
+        let permission: AccessPermission = flags.permission();
+        if permission.is_writable() && permission.is_executable() {
+            panic!("decoded writable and executable mapping flags (flags={:?})", flags);
+        }
+    }
+
+    // For each privilege level, 6 permissions pass W^X. Write-back memory admits all of them as
+    // private or shared, plus the 2 writable ones as copy-on-write. Each uncached policy admits
+    // the 4 non-executable ones as private or shared.
+    if valid != 2 * ((6 * 2 + 2) + 2 * (4 * 2)) {
+        panic!("unexpected number of valid mapping flags (valid={})", valid);
+    }
+
+    // Decode values with reserved bits set.
+    for raw in [0x100_u32, 0x8000_0000] {
+        match MappingFlags::try_from(raw) {
+            Ok(flags) => panic!("decoded reserved bits (raw={:#x}, flags={:?})", raw, flags),
+            Err(e) if e.code == ErrorCode::InvalidArgument => {},
+            Err(e) => panic!("unexpected error code (error={:?})", e),
+        }
+    }
+}
+
+/// Attempts to construct mapping flags with forbidden combinations.
+#[test]
+fn test_mapping_flags_forbidden() {
+    let rwx: AccessPermission = match AccessPermission::try_from(0b111_u8) {
+        Ok(permission) => permission,
+        Err(e) => panic!("failed to decode access permission (error={:?})", e),
+    };
+    let forbidden: [(AccessPermission, CachePolicy, SharingPolicy); 5] = [
+        (rwx, CachePolicy::WriteBack, SharingPolicy::Private),
+        (AccessPermission::EXEC, CachePolicy::Uncached, SharingPolicy::Private),
+        (AccessPermission::RDWR, CachePolicy::WriteCombining, SharingPolicy::CopyOnWrite),
+        (AccessPermission::RDONLY, CachePolicy::WriteBack, SharingPolicy::CopyOnWrite),
+        (AccessPermission::default(), CachePolicy::WriteBack, SharingPolicy::CopyOnWrite),
+    ];
+    for (permission, cache, sharing) in forbidden {
+        match MappingFlags::new(permission, PrivilegeLevel::User, cache, sharing) {
+            Ok(flags) => panic!("constructed forbidden mapping flags (flags={:?})", flags),
+            Err(e) if e.code == ErrorCode::InvalidArgument => {},
+            Err(e) => panic!("unexpected error code (error={:?})", e),
+        }
+    }
+
+    // Construct mapping flags for memory-mapped I/O.
+    match MappingFlags::mmio(AccessPermission::RDWR, CachePolicy::Uncached) {
+        Ok(flags) if flags.is_user() && u32::from(flags) == 0b01_1110 => {},
+        result => panic!("unexpected mmio mapping flags (result={:?})", result),
+    }
+    match MappingFlags::mmio(AccessPermission::RDWR, CachePolicy::WriteBack) {
+        Ok(flags) => panic!("constructed cached mmio mapping flags (flags={:?})", flags),
+        Err(e) if e.code == ErrorCode::InvalidArgument => {},
+        Err(e) => panic!("unexpected error code (error={:?})", e),
+    }
+}
@@ -7,7 +7,14 @@
 
 mod access;
 mod address;
+mod address_space_map;
 mod alignment;
+mod mapping_flags;
+mod page;
+mod region;
+
+/// Paging structures.
+pub mod paging;
 
 //==================================================================================================
 // Exports
@@ -15,4 +22,8 @@ mod alignment;
 
 pub use access::*;
 pub use address::*;
+pub use address_space_map::*;
 pub use alignment::*;
+pub use mapping_flags::*;
+pub use page::*;
+pub use region::*;
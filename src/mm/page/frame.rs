@@ -0,0 +1,275 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::{
+    error::{
+        Error,
+        ErrorCode,
+    },
+    mm::{
+        self,
+        Address,
+        PageSize,
+        PhysicalAddress,
+        Size4KiB,
+    },
+};
+use ::core::marker::PhantomData;
+
+//==================================================================================================
+// Structures
+//==================================================================================================
+
+///
+/// # Description
+///
+/// A frame of physical memory.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Frame<S: PageSize = Size4KiB> {
+    /// Start address of the frame.
+    start: PhysicalAddress,
+    /// Size of the frame.
+    _size: PhantomData<S>,
+}
+
+///
+/// # Description
+///
+/// A range of contiguous frames, which is iterated in increasing order.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FrameRange<S: PageSize = Size4KiB> {
+    /// First frame in the range.
+    start: Frame<S>,
+    /// Frame past the last frame in the range.
+    end: Frame<S>,
+}
+
+//==================================================================================================
+// Implementations
+//==================================================================================================
+
+impl<S: PageSize> Frame<S> {
+    ///
+    /// # Description
+    ///
+    /// Returns the frame that contains a physical address.
+    ///
+    /// # Parameters
+    ///
+    /// - `addr`: The physical address.
+    ///
+    /// # Returns
+    ///
+    /// The frame that contains `addr`.
+    ///
+    pub fn containing_address(addr: PhysicalAddress) -> Self {
+        Self {
+            start: PhysicalAddress::new_unchecked(mm::align_down(
+                addr.into_raw_value(),
+                S::ALIGNMENT,
+            )),
+            _size: PhantomData,
+        }
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the frame that starts at a physical address.
+    ///
+    /// # Parameters
+    ///
+    /// - `addr`: The physical address.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, the frame that starts at `addr` is returned. Upon failure, an error is returned
+    /// instead.
+    ///
+    pub fn from_start_address(addr: PhysicalAddress) -> Result<Self, Error> {
+        if !mm::is_aligned(addr.into_raw_value(), S::ALIGNMENT) {
+            return Err(Error::new(ErrorCode::BadAddress, "unaligned frame address"));
+        }
+        Ok(Self::containing_address(addr))
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the start address of the target frame.
+    ///
+    /// # Returns
+    ///
+    /// The start address of the target frame.
+    ///
+    pub fn start_address(&self) -> PhysicalAddress {
+        self.start
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the number of the target frame.
+    ///
+    /// # Returns
+    ///
+    /// The number of the target frame.
+    ///
+    pub fn number(&self) -> usize {
+        self.start.into_raw_value() / S::SIZE
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the size of the target frame.
+    ///
+    /// # Returns
+    ///
+    /// The size of the target frame (in bytes).
+    ///
+    pub fn size(&self) -> usize {
+        S::SIZE
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the frame that lies `count` frames after the target frame.
+    ///
+    /// # Parameters
+    ///
+    /// - `count`: Number of frames.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, the frame that lies `count` frames after the target frame is returned. Upon
+    /// failure, an error is returned instead.
+    ///
+    pub fn checked_add(&self, count: usize) -> Result<Self, Error> {
+        match count
+            .checked_mul(S::SIZE)
+            .and_then(|offset| self.start.into_raw_value().checked_add(offset))
+        {
+            Some(addr) => Ok(Self::containing_address(PhysicalAddress::new(addr)?)),
+            None => Err(Error::new(ErrorCode::BadAddress, "frame is out of bounds")),
+        }
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the frame that lies `count` frames before the target frame.
+    ///
+    /// # Parameters
+    ///
+    /// - `count`: Number of frames.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, the frame that lies `count` frames before the target frame is returned. Upon
+    /// failure, an error is returned instead.
+    ///
+    pub fn checked_sub(&self, count: usize) -> Result<Self, Error> {
+        match count
+            .checked_mul(S::SIZE)
+            .and_then(|offset| self.start.into_raw_value().checked_sub(offset))
+        {
+            Some(addr) => Ok(Self::containing_address(PhysicalAddress::new(addr)?)),
+            None => Err(Error::new(ErrorCode::BadAddress, "frame is out of bounds")),
+        }
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the range of frames from `start` up to, but not including, `end`.
+    ///
+    /// # Parameters
+    ///
+    /// - `start`: First frame in the range.
+    /// - `end`: Frame past the last frame in the range.
+    ///
+    /// # Returns
+    ///
+    /// The range of frames from `start` up to, but not including, `end`.
+    ///
+    pub fn range(start: Self, end: Self) -> FrameRange<S> {
+        FrameRange { start, end }
+    }
+}
+
+impl<S: PageSize> FrameRange<S> {
+    ///
+    /// # Description
+    ///
+    /// Returns the first frame in the target range.
+    ///
+    /// # Returns
+    ///
+    /// The first frame in the target range.
+    ///
+    pub fn start(&self) -> Frame<S> {
+        self.start
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the frame past the last frame in the target range.
+    ///
+    /// # Returns
+    ///
+    /// The frame past the last frame in the target range.
+    ///
+    pub fn end(&self) -> Frame<S> {
+        self.end
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the number of frames in the target range.
+    ///
+    /// # Returns
+    ///
+    /// The number of frames in the target range.
+    ///
+    pub fn len(&self) -> usize {
+        self.end.number().saturating_sub(self.start.number())
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Checks if the target range is empty.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the target range has no frames, `false` otherwise.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.start >= self.end
+    }
+}
+
+impl<S: PageSize> Iterator for FrameRange<S> {
+    type Item = Frame<S>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let frame: Frame<S> = self.start;
+        // The range ends at the last frame of the address space if the next frame is out of bounds.
+        self.start = self.start.checked_add(1).unwrap_or(self.end);
+        Some(frame)
+    }
+}
@@ -0,0 +1,266 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::{
+    error::{
+        Error,
+        ErrorCode,
+    },
+    mm::{
+        self,
+        Address,
+        PageSize,
+        Size4KiB,
+        VirtualAddress,
+    },
+};
+use ::core::marker::PhantomData;
+
+//==================================================================================================
+// Structures
+//==================================================================================================
+
+///
+/// # Description
+///
+/// A page of virtual memory.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Page<S: PageSize = Size4KiB> {
+    /// Start address of the page.
+    start: VirtualAddress,
+    /// Size of the page.
+    _size: PhantomData<S>,
+}
+
+///
+/// # Description
+///
+/// A range of contiguous pages, which is iterated in increasing order.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PageRange<S: PageSize = Size4KiB> {
+    /// First page in the range.
+    start: Page<S>,
+    /// Page past the last page in the range.
+    end: Page<S>,
+}
+
+//==================================================================================================
+// Implementations
+//==================================================================================================
+
+impl<S: PageSize> Page<S> {
+    ///
+    /// # Description
+    ///
+    /// Returns the page that contains a virtual address.
+    ///
+    /// # Parameters
+    ///
+    /// - `addr`: The virtual address.
+    ///
+    /// # Returns
+    ///
+    /// The page that contains `addr`.
+    ///
+    pub fn containing_address(addr: VirtualAddress) -> Self {
+        Self {
+            start: VirtualAddress::new(mm::align_down(addr.into_raw_value(), S::ALIGNMENT)),
+            _size: PhantomData,
+        }
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the page that starts at a virtual address.
+    ///
+    /// # Parameters
+    ///
+    /// - `addr`: The virtual address.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, the page that starts at `addr` is returned. Upon failure, an error is returned
+    /// instead.
+    ///
+    pub fn from_start_address(addr: VirtualAddress) -> Result<Self, Error> {
+        if !mm::is_aligned(addr.into_raw_value(), S::ALIGNMENT) {
+            return Err(Error::new(ErrorCode::BadAddress, "unaligned page address"));
+        }
+        Ok(Self::containing_address(addr))
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the start address of the target page.
+    ///
+    /// # Returns
+    ///
+    /// The start address of the target page.
+    ///
+    pub fn start_address(&self) -> VirtualAddress {
+        self.start
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the number of the target page.
+    ///
+    /// # Returns
+    ///
+    /// The number of the target page.
+    ///
+    pub fn number(&self) -> usize {
+        self.start.into_raw_value() / S::SIZE
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the size of the target page.
+    ///
+    /// # Returns
+    ///
+    /// The size of the target page (in bytes).
+    ///
+    pub fn size(&self) -> usize {
+        S::SIZE
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the page that lies `count` pages after the target page.
+    ///
+    /// # Parameters
+    ///
+    /// - `count`: Number of pages.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, the page that lies `count` pages after the target page is returned. Upon
+    /// failure, an error is returned instead.
+    ///
+    pub fn checked_add(&self, count: usize) -> Result<Self, Error> {
+        match count.checked_mul(S::SIZE) {
+            Some(offset) => Ok(Self::containing_address(self.start.checked_add(offset)?)),
+            None => Err(Error::new(ErrorCode::BadAddress, "page is out of bounds")),
+        }
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the page that lies `count` pages before the target page.
+    ///
+    /// # Parameters
+    ///
+    /// - `count`: Number of pages.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, the page that lies `count` pages before the target page is returned. Upon
+    /// failure, an error is returned instead.
+    ///
+    pub fn checked_sub(&self, count: usize) -> Result<Self, Error> {
+        match count.checked_mul(S::SIZE) {
+            Some(offset) => Ok(Self::containing_address(self.start.checked_sub(offset)?)),
+            None => Err(Error::new(ErrorCode::BadAddress, "page is out of bounds")),
+        }
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the range of pages from `start` up to, but not including, `end`.
+    ///
+    /// # Parameters
+    ///
+    /// - `start`: First page in the range.
+    /// - `end`: Page past the last page in the range.
+    ///
+    /// # Returns
+    ///
+    /// The range of pages from `start` up to, but not including, `end`.
+    ///
+    pub fn range(start: Self, end: Self) -> PageRange<S> {
+        PageRange { start, end }
+    }
+}
+
+impl<S: PageSize> PageRange<S> {
+    ///
+    /// # Description
+    ///
+    /// Returns the first page in the target range.
+    ///
+    /// # Returns
+    ///
+    /// The first page in the target range.
+    ///
+    pub fn start(&self) -> Page<S> {
+        self.start
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the page past the last page in the target range.
+    ///
+    /// # Returns
+    ///
+    /// The page past the last page in the target range.
+    ///
+    pub fn end(&self) -> Page<S> {
+        self.end
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the number of pages in the target range.
+    ///
+    /// # Returns
+    ///
+    /// The number of pages in the target range.
+    ///
+    pub fn len(&self) -> usize {
+        self.end.number().saturating_sub(self.start.number())
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Checks if the target range is empty.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the target range has no pages, `false` otherwise.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.start >= self.end
+    }
+}
+
+impl<S: PageSize> Iterator for PageRange<S> {
+    type Item = Page<S>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let page: Page<S> = self.start;
+        // The range ends at the last page of the address space if the next page is out of bounds.
+        self.start = self.start.checked_add(1).unwrap_or(self.end);
+        Some(page)
+    }
+}
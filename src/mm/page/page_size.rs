@@ -0,0 +1,69 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::mm::Alignment;
+
+//==================================================================================================
+// Structures
+//==================================================================================================
+
+///
+/// # Description
+///
+/// Pages of 4 KiB.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Size4KiB;
+
+///
+/// # Description
+///
+/// Pages of 2 MiB.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Size2MiB;
+
+///
+/// # Description
+///
+/// Pages of 4 MiB.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Size4MiB;
+
+//==================================================================================================
+// Traits
+//==================================================================================================
+
+///
+/// # Description
+///
+/// A trait for sizes of pages and frames.
+///
+pub trait PageSize: core::fmt::Debug + Clone + Copy + PartialEq + Eq + PartialOrd + Ord {
+    /// Alignment of pages, which matches their size.
+    const ALIGNMENT: Alignment;
+
+    /// Size of pages (in bytes).
+    const SIZE: usize = Self::ALIGNMENT as usize;
+}
+
+//==================================================================================================
+// Implementations
+//==================================================================================================
+
+impl PageSize for Size4KiB {
+    const ALIGNMENT: Alignment = Alignment::Align4096;
+}
+
+impl PageSize for Size2MiB {
+    const ALIGNMENT: Alignment = Alignment::Align2097152;
+}
+
+impl PageSize for Size4MiB {
+    const ALIGNMENT: Alignment = Alignment::Align4194304;
+}
@@ -0,0 +1,106 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::{
+    error::ErrorCode,
+    mm::{
+        Frame,
+        FrameRange,
+        Page,
+        PageRange,
+        PhysicalAddress,
+        Size2MiB,
+        Size4KiB,
+        Size4MiB,
+        VirtualAddress,
+    },
+};
+use ::alloc::vec::Vec;
+
+//==================================================================================================
+// Unit Tests
+//==================================================================================================
+
+/// Attempts to compute pages that contain virtual addresses.
+#[test]
+fn test_containing_address() {
+    let addr: VirtualAddress = VirtualAddress::new(0x00a0_1234);
+
+    let page: Page = Page::containing_address(addr);
+    if page.start_address() != VirtualAddress::new(0x00a0_1000) || page.number() != 0xa01 {
+        panic!("unexpected 4 KiB page (page={:?})", page);
+    }
+    let page: Page<Size2MiB> = Page::containing_address(addr);
+    if page.start_address() != VirtualAddress::new(0x00a0_0000) || page.size() != 0x20_0000 {
+        panic!("unexpected 2 MiB page (page={:?})", page);
+    }
+    let page: Page<Size4MiB> = Page::containing_address(addr);
+    if page.start_address() != VirtualAddress::new(0x0080_0000) || page.number() != 2 {
+        panic!("unexpected 4 MiB page (page={:?})", page);
+    }
+
+    match Page::<Size4KiB>::from_start_address(addr) {
+        Ok(page) => panic!("created page from unaligned address (page={:?})", page),
+        Err(e) if e.code == ErrorCode::BadAddress => {},
+        Err(e) => panic!("unexpected error code (error={:?})", e),
+    }
+}
+
+/// Attempts to move pages and frames past the bounds of the address space.
+#[test]
+fn test_checked_arithmetic() {
+    let page: Page = Page::containing_address(VirtualAddress::new(0x3000));
+    match page.checked_add(2) {
+        Ok(next) if next.number() == 5 => {},
+        result => panic!("unexpected page (result={:?})", result),
+    }
+    match page.checked_sub(4) {
+        Ok(prev) => panic!("moved page below the address space (page={:?})", prev),
+        Err(e) if e.code == ErrorCode::BadAddress => {},
+        Err(e) => panic!("unexpected error code (error={:?})", e),
+    }
+
+    let frame: Frame = Frame::containing_address(PhysicalAddress::new_unchecked(usize::MAX));
+    match frame.checked_add(1) {
+        Ok(next) => panic!("moved frame past the address space (frame={:?})", next),
+        Err(e) if e.code == ErrorCode::BadAddress => {},
+        Err(e) => panic!("unexpected error code (error={:?})", e),
+    }
+}
+
+/// Attempts to iterate over ranges of pages and frames.
+#[test]
+fn test_ranges() {
+    let start: Page = Page::containing_address(VirtualAddress::new(0x1000));
+    let end: Page = Page::containing_address(VirtualAddress::new(0x4000));
+    let range: PageRange = Page::range(start, end);
+    if range.len() != 3 || range.is_empty() {
+        panic!("unexpected length of range (len={})", range.len());
+    }
+    let numbers: Vec<usize> = range.map(|page| page.number()).collect();
+    if numbers != [1, 2, 3] {
+        panic!("unexpected pages (got={:?})", numbers);
+    }
+    if Page::range(end, start).next().is_some() {
+        panic!("iterated over a reversed range");
+    }
+
+    // Check if a range that ends at the top of the address space terminates.
+    let last: Frame = Frame::containing_address(PhysicalAddress::new_unchecked(usize::MAX));
+    let range: FrameRange = Frame::range(last, Frame::containing_address(last.start_address()));
+    if range.count() != 0 {
+        panic!("iterated over an empty range");
+    }
+    let first: Frame = match last.checked_sub(1) {
+        Ok(frame) => frame,
+        Err(e) => panic!("failed to move frame (error={:?})", e),
+    };
+    let range: FrameRange = Frame::range(first, last);
+    if range.count() != 1 {
+        panic!("unexpected number of frames");
+    }
+}
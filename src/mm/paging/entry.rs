@@ -0,0 +1,255 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::{
+    error::{
+        Error,
+        ErrorCode,
+    },
+    mm::{
+        AccessPermission,
+        ExecutePermission,
+        Frame,
+        ReadPermission,
+        WritePermission,
+    },
+};
+
+//==================================================================================================
+// Traits
+//==================================================================================================
+
+///
+/// # Description
+///
+/// A trait for entries of page tables, independent of how an architecture lays them out.
+///
+/// Access permissions are encoded on top of the present, writable and no-execute flags. Present
+/// entries are always readable, thus permissions that deny reading but allow writing or executing
+/// cannot be encoded.
+///
+pub trait PageTableEntry: Copy + Default + core::fmt::Debug {
+    ///
+    /// # Description
+    ///
+    /// Checks if the target entry is present.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the target entry is present, `false` otherwise.
+    ///
+    fn is_present(&self) -> bool;
+
+    ///
+    /// # Description
+    ///
+    /// Sets or clears the present flag of the target entry.
+    ///
+    /// # Parameters
+    ///
+    /// - `present`: Value of the flag.
+    ///
+    fn set_present(&mut self, present: bool);
+
+    ///
+    /// # Description
+    ///
+    /// Checks if the target entry allows write access.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the target entry allows write access, `false` otherwise.
+    ///
+    fn is_writable(&self) -> bool;
+
+    ///
+    /// # Description
+    ///
+    /// Sets or clears the writable flag of the target entry.
+    ///
+    /// # Parameters
+    ///
+    /// - `writable`: Value of the flag.
+    ///
+    fn set_writable(&mut self, writable: bool);
+
+    ///
+    /// # Description
+    ///
+    /// Checks if the target entry allows access from user mode.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the target entry allows access from user mode, `false` otherwise.
+    ///
+    fn is_user(&self) -> bool;
+
+    ///
+    /// # Description
+    ///
+    /// Sets or clears the user flag of the target entry.
+    ///
+    /// # Parameters
+    ///
+    /// - `user`: Value of the flag.
+    ///
+    fn set_user(&mut self, user: bool);
+
+    ///
+    /// # Description
+    ///
+    /// Checks if the target entry was accessed.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the target entry was accessed, `false` otherwise.
+    ///
+    fn is_accessed(&self) -> bool;
+
+    ///
+    /// # Description
+    ///
+    /// Sets or clears the accessed flag of the target entry.
+    ///
+    /// # Parameters
+    ///
+    /// - `accessed`: Value of the flag.
+    ///
+    fn set_accessed(&mut self, accessed: bool);
+
+    ///
+    /// # Description
+    ///
+    /// Checks if the target entry was written to.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the target entry was written to, `false` otherwise.
+    ///
+    fn is_dirty(&self) -> bool;
+
+    ///
+    /// # Description
+    ///
+    /// Sets or clears the dirty flag of the target entry.
+    ///
+    /// # Parameters
+    ///
+    /// - `dirty`: Value of the flag.
+    ///
+    fn set_dirty(&mut self, dirty: bool);
+
+    ///
+    /// # Description
+    ///
+    /// Checks if the target entry denies execute access.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the target entry denies execute access, `false` otherwise.
+    ///
+    fn is_no_execute(&self) -> bool;
+
+    ///
+    /// # Description
+    ///
+    /// Sets or clears the no-execute flag of the target entry.
+    ///
+    /// # Parameters
+    ///
+    /// - `no_execute`: Value of the flag.
+    ///
+    fn set_no_execute(&mut self, no_execute: bool);
+
+    ///
+    /// # Description
+    ///
+    /// Returns the frame that the target entry refers to.
+    ///
+    /// # Returns
+    ///
+    /// The frame that the target entry refers to.
+    ///
+    fn frame(&self) -> Frame;
+
+    ///
+    /// # Description
+    ///
+    /// Sets the frame that the target entry refers to.
+    ///
+    /// # Parameters
+    ///
+    /// - `frame`: Target frame.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, `Ok(())` is returned. Upon failure, an error is returned instead.
+    ///
+    fn set_frame(&mut self, frame: Frame) -> Result<(), Error>;
+
+    ///
+    /// # Description
+    ///
+    /// Decodes the access permissions of the target entry.
+    ///
+    /// # Returns
+    ///
+    /// The access permissions of the target entry. Entries that are not present deny all accesses.
+    ///
+    fn permission(&self) -> AccessPermission {
+        if !self.is_present() {
+            return AccessPermission::default();
+        }
+
+        AccessPermission::new(
+            ReadPermission::Allow,
+            if self.is_writable() {
+                WritePermission::Allow
+            } else {
+                WritePermission::Deny
+            },
+            if self.is_no_execute() {
+                ExecutePermission::Deny
+            } else {
+                ExecutePermission::Allow
+            },
+        )
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Encodes access permissions in the target entry. Permissions that deny all accesses clear
+    /// the present flag.
+    ///
+    /// # Parameters
+    ///
+    /// - `permission`: Access permissions to encode.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, `Ok(())` is returned. Upon failure, an error is returned instead.
+    ///
+    fn set_permission(&mut self, permission: AccessPermission) -> Result<(), Error> {
+        match (permission.is_readable(), permission.is_writable(), permission.is_executable()) {
+            (false, false, false) => self.set_present(false),
+            (true, writable, executable) => {
+                self.set_present(true);
+                self.set_writable(writable);
+                self.set_no_execute(!executable);
+            },
+            _ => {
+                return Err(Error::new(
+                    ErrorCode::OperationNotSupported,
+                    "access permission cannot be encoded",
+                ))
+            },
+        }
+
+        Ok(())
+    }
+}
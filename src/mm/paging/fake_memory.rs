@@ -0,0 +1,136 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::{
+    error::{
+        Error,
+        ErrorCode,
+    },
+    mm::{
+        paging::PhysicalMemory,
+        Address,
+        PhysicalAddress,
+    },
+};
+use ::alloc::{
+    vec,
+    vec::Vec,
+};
+
+//==================================================================================================
+// Structures
+//==================================================================================================
+
+///
+/// # Description
+///
+/// Physical memory that is simulated with a buffer on the heap, so that page tables can be built
+/// and walked on the host.
+///
+/// The buffer spans physical addresses from zero up to its length.
+///
+#[derive(Debug)]
+pub struct FakePhysicalMemory {
+    /// Contents of the memory.
+    bytes: Vec<u8>,
+}
+
+//==================================================================================================
+// Implementations
+//==================================================================================================
+
+impl FakePhysicalMemory {
+    ///
+    /// # Description
+    ///
+    /// Creates a new zero-filled fake physical memory.
+    ///
+    /// # Parameters
+    ///
+    /// - `len`: Length of the memory (in bytes).
+    ///
+    /// # Returns
+    ///
+    /// A new zero-filled fake physical memory.
+    ///
+    pub fn new(len: usize) -> Self {
+        Self {
+            bytes: vec![0; len],
+        }
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the length of the memory.
+    ///
+    /// # Returns
+    ///
+    /// The length of the memory (in bytes).
+    ///
+    pub fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Checks if the memory is empty.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the memory has no bytes, `false` otherwise.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Computes the offset of a 32-bit word in the memory.
+    ///
+    /// # Parameters
+    ///
+    /// - `addr`: Physical address of the word.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, the offset of the word is returned. Upon failure, an error is returned
+    /// instead.
+    ///
+    fn offset_of(&self, addr: PhysicalAddress) -> Result<usize, Error> {
+        let offset: usize = addr.into_raw_value();
+
+        // Check if the word is unaligned.
+        if offset % core::mem::size_of::<u32>() != 0 {
+            return Err(Error::new(ErrorCode::BadAddress, "unaligned physical address"));
+        }
+
+        // Check if the word lies outside of the memory.
+        match offset.checked_add(core::mem::size_of::<u32>()) {
+            Some(end) if end <= self.bytes.len() => Ok(offset),
+            _ => Err(Error::new(ErrorCode::BadAddress, "physical address out of bounds")),
+        }
+    }
+}
+
+impl PhysicalMemory for FakePhysicalMemory {
+    fn read_u32(&self, addr: PhysicalAddress) -> Result<u32, Error> {
+        let offset: usize = self.offset_of(addr)?;
+        let mut word: [u8; 4] = [0; 4];
+        word.copy_from_slice(&self.bytes[offset..offset + core::mem::size_of::<u32>()]);
+        Ok(u32::from_le_bytes(word))
+    }
+
+    fn write_u32(&mut self, addr: PhysicalAddress, value: u32) -> Result<(), Error> {
+        let offset: usize = self.offset_of(addr)?;
+        self.bytes[offset..offset + core::mem::size_of::<u32>()]
+            .copy_from_slice(&value.to_le_bytes());
+        Ok(())
+    }
+}
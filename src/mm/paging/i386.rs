@@ -0,0 +1,224 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::{
+    error::{
+        Error,
+        ErrorCode,
+    },
+    mm::{
+        paging::PageTableEntry,
+        Address,
+        Frame,
+        PhysicalAddress,
+        VirtualAddress,
+    },
+};
+
+//==================================================================================================
+// Structures
+//==================================================================================================
+
+///
+/// # Description
+///
+/// An entry of a page directory or page table of the i386 two-level paging scheme.
+///
+/// Entries of both levels share this layout. The dirty flag is ignored by hardware in page
+/// directory entries. The i386 architecture has no no-execute flag without PAE, thus the
+/// no-execute flag is kept in a bit that is available to software and is enforced by the kernel
+/// only.
+///
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct I386PageTableEntry(u32);
+crate::static_assert_size!(I386PageTableEntry, 4);
+
+//==================================================================================================
+// Implementations
+//==================================================================================================
+
+impl I386PageTableEntry {
+    /// Number of entries in a page directory or page table.
+    pub const ENTRIES_PER_TABLE: usize = 1024;
+
+    /// Present flag.
+    const PRESENT: u32 = 1 << 0;
+    /// Writable flag.
+    const WRITABLE: u32 = 1 << 1;
+    /// User flag.
+    const USER: u32 = 1 << 2;
+    /// Accessed flag.
+    const ACCESSED: u32 = 1 << 5;
+    /// Dirty flag.
+    const DIRTY: u32 = 1 << 6;
+    /// No-execute flag, in the first bit that is available to software.
+    const NO_EXECUTE: u32 = 1 << 9;
+    /// Mask of the frame address.
+    const FRAME_MASK: u32 = 0xffff_f000;
+
+    ///
+    /// # Description
+    ///
+    /// Instantiates a new [`I386PageTableEntry`] from its raw value.
+    ///
+    /// # Parameters
+    ///
+    /// - `raw`: The raw value.
+    ///
+    /// # Returns
+    ///
+    /// The new entry.
+    ///
+    pub const fn from_raw(raw: u32) -> Self {
+        Self(raw)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the raw value of the target entry.
+    ///
+    /// # Returns
+    ///
+    /// The raw value of the target entry.
+    ///
+    pub const fn into_raw(self) -> u32 {
+        self.0
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Computes the index of the page directory entry that maps a virtual address.
+    ///
+    /// # Parameters
+    ///
+    /// - `addr`: Target virtual address.
+    ///
+    /// # Returns
+    ///
+    /// The index of the page directory entry that maps `addr`.
+    ///
+    pub fn directory_index(addr: VirtualAddress) -> usize {
+        (addr.into_raw_value() >> 22) & (Self::ENTRIES_PER_TABLE - 1)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Computes the index of the page table entry that maps a virtual address.
+    ///
+    /// # Parameters
+    ///
+    /// - `addr`: Target virtual address.
+    ///
+    /// # Returns
+    ///
+    /// The index of the page table entry that maps `addr`.
+    ///
+    pub fn table_index(addr: VirtualAddress) -> usize {
+        (addr.into_raw_value() >> 12) & (Self::ENTRIES_PER_TABLE - 1)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Sets or clears flags of the target entry.
+    ///
+    /// # Parameters
+    ///
+    /// - `flags`: Target flags.
+    /// - `value`: `true` to set the flags, `false` to clear them.
+    ///
+    fn set_flags(&mut self, flags: u32, value: bool) {
+        if value {
+            self.0 |= flags;
+        } else {
+            self.0 &= !flags;
+        }
+    }
+}
+
+impl PageTableEntry for I386PageTableEntry {
+    fn is_present(&self) -> bool {
+        self.0 & Self::PRESENT != 0
+    }
+
+    fn set_present(&mut self, present: bool) {
+        self.set_flags(Self::PRESENT, present)
+    }
+
+    fn is_writable(&self) -> bool {
+        self.0 & Self::WRITABLE != 0
+    }
+
+    fn set_writable(&mut self, writable: bool) {
+        self.set_flags(Self::WRITABLE, writable)
+    }
+
+    fn is_user(&self) -> bool {
+        self.0 & Self::USER != 0
+    }
+
+    fn set_user(&mut self, user: bool) {
+        self.set_flags(Self::USER, user)
+    }
+
+    fn is_accessed(&self) -> bool {
+        self.0 & Self::ACCESSED != 0
+    }
+
+    fn set_accessed(&mut self, accessed: bool) {
+        self.set_flags(Self::ACCESSED, accessed)
+    }
+
+    fn is_dirty(&self) -> bool {
+        self.0 & Self::DIRTY != 0
+    }
+
+    fn set_dirty(&mut self, dirty: bool) {
+        self.set_flags(Self::DIRTY, dirty)
+    }
+
+    fn is_no_execute(&self) -> bool {
+        self.0 & Self::NO_EXECUTE != 0
+    }
+
+    fn set_no_execute(&mut self, no_execute: bool) {
+        self.set_flags(Self::NO_EXECUTE, no_execute)
+    }
+
+    fn frame(&self) -> Frame {
+        Frame::containing_address(PhysicalAddress::new_unchecked(
+            (self.0 & Self::FRAME_MASK) as usize,
+        ))
+    }
+
+    fn set_frame(&mut self, frame: Frame) -> Result<(), Error> {
+        let addr: u32 = match u32::try_from(frame.start_address().into_raw_value()) {
+            Ok(addr) => addr,
+            Err(_) => return Err(Error::new(ErrorCode::BadAddress, "frame does not fit in entry")),
+        };
+        self.0 = (self.0 & !Self::FRAME_MASK) | addr;
+        Ok(())
+    }
+}
+
+impl core::fmt::Debug for I386PageTableEntry {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "{:?} {:?} {}{}{}",
+            self.frame().start_address(),
+            self.permission(),
+            if self.is_user() { "u" } else { "s" },
+            if self.is_accessed() { "a" } else { "-" },
+            if self.is_dirty() { "d" } else { "-" },
+        )
+    }
+}
@@ -0,0 +1,568 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::{
+    config::memory_layout,
+    error::{
+        Error,
+        ErrorCode,
+    },
+    mm::{
+        paging::{
+            I386PageTableEntry,
+            PageTableEntry,
+        },
+        AccessPermission,
+        Address,
+        Frame,
+        Page,
+        PhysicalAddress,
+        VirtualAddress,
+    },
+};
+
+//==================================================================================================
+// Structures
+//==================================================================================================
+
+///
+/// # Description
+///
+/// A mapper that walks and edits i386 two-level page tables in software.
+///
+/// Page tables are read and written through a [`PhysicalMemory`] accessor, and frames for page
+/// tables are taken from a [`FrameAllocator`]. Page directory entries are permissive, so access
+/// permissions are enforced by page table entries only.
+///
+#[derive(Debug)]
+pub struct PageTableMapper<A: FrameAllocator, M: PhysicalMemory> {
+    /// Frame that holds the page directory.
+    directory: Frame,
+    /// Allocator of frames for page tables.
+    allocator: A,
+    /// Accessor of physical memory.
+    memory: M,
+}
+
+//==================================================================================================
+// Traits
+//==================================================================================================
+
+///
+/// # Description
+///
+/// A trait for allocators of physical frames.
+///
+pub trait FrameAllocator {
+    ///
+    /// # Description
+    ///
+    /// Allocates a frame.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, the allocated frame is returned. Upon failure, an error is returned instead.
+    ///
+    fn allocate_frame(&mut self) -> Result<Frame, Error>;
+
+    ///
+    /// # Description
+    ///
+    /// Releases a frame.
+    ///
+    /// # Parameters
+    ///
+    /// - `frame`: Frame to release.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, `Ok(())` is returned. Upon failure, an error is returned instead.
+    ///
+    fn deallocate_frame(&mut self, frame: Frame) -> Result<(), Error>;
+}
+
+///
+/// # Description
+///
+/// A trait for accessors of physical memory.
+///
+pub trait PhysicalMemory {
+    ///
+    /// # Description
+    ///
+    /// Reads a 32-bit word from physical memory.
+    ///
+    /// # Parameters
+    ///
+    /// - `addr`: Physical address of the word.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, the word is returned. Upon failure, an error is returned instead.
+    ///
+    fn read_u32(&self, addr: PhysicalAddress) -> Result<u32, Error>;
+
+    ///
+    /// # Description
+    ///
+    /// Writes a 32-bit word to physical memory.
+    ///
+    /// # Parameters
+    ///
+    /// - `addr`: Physical address of the word.
+    /// - `value`: Value of the word.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, `Ok(())` is returned. Upon failure, an error is returned instead.
+    ///
+    fn write_u32(&mut self, addr: PhysicalAddress, value: u32) -> Result<(), Error>;
+}
+
+//==================================================================================================
+// Implementations
+//==================================================================================================
+
+impl<A: FrameAllocator, M: PhysicalMemory> PageTableMapper<A, M> {
+    ///
+    /// # Description
+    ///
+    /// Creates a new mapper with an empty page directory.
+    ///
+    /// # Parameters
+    ///
+    /// - `allocator`: Allocator of frames for page tables.
+    /// - `memory`: Accessor of physical memory.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, a new mapper is returned. Upon failure, an error is returned instead.
+    ///
+    pub fn new(mut allocator: A, mut memory: M) -> Result<Self, Error> {
+        let directory: Frame = allocator.allocate_frame()?;
+        clear_frame(&mut memory, directory)?;
+        Ok(Self::from_directory(directory, allocator, memory))
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Creates a new mapper for an existing page directory.
+    ///
+    /// # Parameters
+    ///
+    /// - `directory`: Frame that holds the page directory.
+    /// - `allocator`: Allocator of frames for page tables.
+    /// - `memory`: Accessor of physical memory.
+    ///
+    /// # Returns
+    ///
+    /// A new mapper.
+    ///
+    pub fn from_directory(directory: Frame, allocator: A, memory: M) -> Self {
+        Self {
+            directory,
+            allocator,
+            memory,
+        }
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the frame that holds the page directory.
+    ///
+    /// # Returns
+    ///
+    /// The frame that holds the page directory.
+    ///
+    pub fn directory(&self) -> Frame {
+        self.directory
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the accessor of physical memory.
+    ///
+    /// # Returns
+    ///
+    /// The accessor of physical memory.
+    ///
+    pub fn memory(&self) -> &M {
+        &self.memory
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the allocator of frames for page tables.
+    ///
+    /// # Returns
+    ///
+    /// The allocator of frames for page tables.
+    ///
+    pub fn allocator(&self) -> &A {
+        &self.allocator
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Maps a page to a frame. A page table is allocated if none covers the page yet. Pages that
+    /// lie in user space are accessible from user mode.
+    ///
+    /// # Parameters
+    ///
+    /// - `page`: Page to map.
+    /// - `frame`: Frame to map the page to.
+    /// - `permission`: Access permissions of the mapping.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, `Ok(())` is returned. Upon failure, an error is returned instead.
+    ///
+    pub fn map(
+        &mut self,
+        page: Page,
+        frame: Frame,
+        permission: AccessPermission,
+    ) -> Result<(), Error> {
+        check_address(page.start_address())?;
+
+        let mut entry: I386PageTableEntry = I386PageTableEntry::default();
+        entry.set_frame(frame)?;
+        set_permission(&mut entry, permission)?;
+        entry.set_user(is_user(page));
+
+        let table: Frame = self.table_or_allocate(page)?;
+        let addr: PhysicalAddress =
+            entry_address(table, I386PageTableEntry::table_index(page.start_address()))?;
+
+        // Check if the page is already mapped.
+        if read_entry(&self.memory, addr)?.is_present() {
+            return Err(Error::new(ErrorCode::EntryExists, "page is already mapped"));
+        }
+
+        write_entry(&mut self.memory, addr, entry)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Unmaps a page. The page table that covered the page is released if it becomes empty.
+    ///
+    /// # Parameters
+    ///
+    /// - `page`: Page to unmap.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, the frame that the page was mapped to is returned. Upon failure, an error is
+    /// returned instead.
+    ///
+    pub fn unmap(&mut self, page: Page) -> Result<Frame, Error> {
+        check_address(page.start_address())?;
+        let (table, addr): (Frame, PhysicalAddress) = self.lookup(page)?;
+        let entry: I386PageTableEntry = read_entry(&self.memory, addr)?;
+        write_entry(&mut self.memory, addr, I386PageTableEntry::default())?;
+
+        // Release the page table if it has no entries left.
+        for index in 0..I386PageTableEntry::ENTRIES_PER_TABLE {
+            if read_entry(&self.memory, entry_address(table, index)?)?.is_present() {
+                return Ok(entry.frame());
+            }
+        }
+        let directory_index: usize = I386PageTableEntry::directory_index(page.start_address());
+        write_entry(
+            &mut self.memory,
+            entry_address(self.directory, directory_index)?,
+            I386PageTableEntry::default(),
+        )?;
+        self.allocator.deallocate_frame(table)?;
+
+        Ok(entry.frame())
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Translates a virtual address into the physical address that it is mapped to.
+    ///
+    /// # Parameters
+    ///
+    /// - `addr`: Virtual address to translate.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, the physical address that `addr` is mapped to is returned. Upon failure, an
+    /// error is returned instead.
+    ///
+    pub fn translate(&self, addr: VirtualAddress) -> Result<PhysicalAddress, Error> {
+        check_address(addr)?;
+        let page: Page = Page::containing_address(addr);
+        let (_, entry_addr): (Frame, PhysicalAddress) = self.lookup(page)?;
+        let frame: Frame = read_entry(&self.memory, entry_addr)?.frame();
+        PhysicalAddress::new(
+            frame.start_address().into_raw_value() + addr.offset_from(page.start_address())?,
+        )
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Changes the access permissions of a mapped page.
+    ///
+    /// # Parameters
+    ///
+    /// - `page`: Target page.
+    /// - `permission`: New access permissions.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, `Ok(())` is returned. Upon failure, an error is returned instead.
+    ///
+    pub fn update_flags(&mut self, page: Page, permission: AccessPermission) -> Result<(), Error> {
+        check_address(page.start_address())?;
+        let (_, addr): (Frame, PhysicalAddress) = self.lookup(page)?;
+        let mut entry: I386PageTableEntry = read_entry(&self.memory, addr)?;
+        set_permission(&mut entry, permission)?;
+        write_entry(&mut self.memory, addr, entry)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Looks up the page table entry that maps a page.
+    ///
+    /// # Parameters
+    ///
+    /// - `page`: Target page.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, the frame of the page table and the physical address of the entry are
+    /// returned. Upon failure, an error is returned instead.
+    ///
+    fn lookup(&self, page: Page) -> Result<(Frame, PhysicalAddress), Error> {
+        let directory_entry: I386PageTableEntry = read_entry(
+            &self.memory,
+            entry_address(
+                self.directory,
+                I386PageTableEntry::directory_index(page.start_address()),
+            )?,
+        )?;
+        if !directory_entry.is_present() {
+            return Err(Error::new(ErrorCode::BadAddress, "page is not mapped"));
+        }
+
+        let table: Frame = directory_entry.frame();
+        let addr: PhysicalAddress =
+            entry_address(table, I386PageTableEntry::table_index(page.start_address()))?;
+        if !read_entry(&self.memory, addr)?.is_present() {
+            return Err(Error::new(ErrorCode::BadAddress, "page is not mapped"));
+        }
+
+        Ok((table, addr))
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the page table that covers a page, allocating it if needed.
+    ///
+    /// # Parameters
+    ///
+    /// - `page`: Target page.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, the frame of the page table is returned. Upon failure, an error is returned
+    /// instead.
+    ///
+    fn table_or_allocate(&mut self, page: Page) -> Result<Frame, Error> {
+        let addr: PhysicalAddress = entry_address(
+            self.directory,
+            I386PageTableEntry::directory_index(page.start_address()),
+        )?;
+        let directory_entry: I386PageTableEntry = read_entry(&self.memory, addr)?;
+        if directory_entry.is_present() {
+            return Ok(directory_entry.frame());
+        }
+
+        let table: Frame = self.allocator.allocate_frame()?;
+        let mut directory_entry: I386PageTableEntry = I386PageTableEntry::default();
+        if let Err(e) = directory_entry
+            .set_frame(table)
+            .and_then(|_| clear_frame(&mut self.memory, table))
+        {
+            let _ = self.allocator.deallocate_frame(table);
+            return Err(e);
+        }
+        directory_entry.set_present(true);
+        directory_entry.set_writable(true);
+        directory_entry.set_user(true);
+        write_entry(&mut self.memory, addr, directory_entry)?;
+
+        Ok(table)
+    }
+}
+
+//==================================================================================================
+// Standalone Functions
+//==================================================================================================
+
+///
+/// # Description
+///
+/// Checks if a virtual address lies in the 32-bit address space that page tables cover.
+/// Addresses above it would otherwise alias lower ones, as page table indexes are masked.
+///
+/// # Parameters
+///
+/// - `addr`: Target virtual address.
+///
+/// # Returns
+///
+/// Upon success, `Ok(())` is returned. Upon failure, an error is returned instead.
+///
+fn check_address(addr: VirtualAddress) -> Result<(), Error> {
+    if u32::try_from(addr.into_raw_value()).is_err() {
+        return Err(Error::new(ErrorCode::BadAddress, "virtual address does not fit in 32 bits"));
+    }
+
+    Ok(())
+}
+
+///
+/// # Description
+///
+/// Computes the physical address of an entry of a page directory or page table.
+///
+/// # Parameters
+///
+/// - `table`: Frame that holds the page directory or page table.
+/// - `index`: Index of the entry.
+///
+/// # Returns
+///
+/// Upon success, the physical address of the entry is returned. Upon failure, an error is returned
+/// instead.
+///
+fn entry_address(table: Frame, index: usize) -> Result<PhysicalAddress, Error> {
+    PhysicalAddress::new(
+        table.start_address().into_raw_value() + index * core::mem::size_of::<I386PageTableEntry>(),
+    )
+}
+
+///
+/// # Description
+///
+/// Reads an entry of a page directory or page table.
+///
+/// # Parameters
+///
+/// - `memory`: Accessor of physical memory.
+/// - `addr`: Physical address of the entry.
+///
+/// # Returns
+///
+/// Upon success, the entry is returned. Upon failure, an error is returned instead.
+///
+fn read_entry<M: PhysicalMemory>(
+    memory: &M,
+    addr: PhysicalAddress,
+) -> Result<I386PageTableEntry, Error> {
+    Ok(I386PageTableEntry::from_raw(memory.read_u32(addr)?))
+}
+
+///
+/// # Description
+///
+/// Writes an entry of a page directory or page table.
+///
+/// # Parameters
+///
+/// - `memory`: Accessor of physical memory.
+/// - `addr`: Physical address of the entry.
+/// - `entry`: Entry to write.
+///
+/// # Returns
+///
+/// Upon success, `Ok(())` is returned. Upon failure, an error is returned instead.
+///
+fn write_entry<M: PhysicalMemory>(
+    memory: &mut M,
+    addr: PhysicalAddress,
+    entry: I386PageTableEntry,
+) -> Result<(), Error> {
+    memory.write_u32(addr, entry.into_raw())
+}
+
+///
+/// # Description
+///
+/// Fills a frame with zeros.
+///
+/// # Parameters
+///
+/// - `memory`: Accessor of physical memory.
+/// - `frame`: Target frame.
+///
+/// # Returns
+///
+/// Upon success, `Ok(())` is returned. Upon failure, an error is returned instead.
+///
+fn clear_frame<M: PhysicalMemory>(memory: &mut M, frame: Frame) -> Result<(), Error> {
+    for index in 0..I386PageTableEntry::ENTRIES_PER_TABLE {
+        write_entry(memory, entry_address(frame, index)?, I386PageTableEntry::default())?;
+    }
+    Ok(())
+}
+
+///
+/// # Description
+///
+/// Encodes access permissions of a mapping in a page table entry.
+///
+/// # Parameters
+///
+/// - `entry`: Target entry.
+/// - `permission`: Access permissions of the mapping. They must allow some access, otherwise the
+///   mapping would be lost.
+///
+/// # Returns
+///
+/// Upon success, `Ok(())` is returned. Upon failure, an error is returned instead.
+///
+fn set_permission(
+    entry: &mut I386PageTableEntry,
+    permission: AccessPermission,
+) -> Result<(), Error> {
+    if permission == AccessPermission::default() {
+        return Err(Error::new(ErrorCode::InvalidArgument, "permission denies all accesses"));
+    }
+    entry.set_permission(permission)
+}
+
+///
+/// # Description
+///
+/// Checks if a page lies in user space.
+///
+/// # Parameters
+///
+/// - `page`: Target page.
+///
+/// # Returns
+///
+/// `true` if the page lies in user space, `false` otherwise.
+///
+fn is_user(page: Page) -> bool {
+    page.start_address() >= memory_layout::USER_BASE
+        && page.start_address() < memory_layout::USER_END
+}
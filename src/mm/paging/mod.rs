@@ -0,0 +1,23 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Modules
+//==================================================================================================
+
+mod entry;
+mod fake_memory;
+mod i386;
+mod mapper;
+
+#[cfg(test)]
+mod test;
+
+//==================================================================================================
+// Exports
+//==================================================================================================
+
+pub use entry::*;
+pub use fake_memory::*;
+pub use i386::*;
+pub use mapper::*;
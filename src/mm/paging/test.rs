@@ -0,0 +1,362 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::{
+    config::memory_layout,
+    error::{
+        Error,
+        ErrorCode,
+    },
+    mm::{
+        paging::{
+            FakePhysicalMemory,
+            FrameAllocator,
+            I386PageTableEntry,
+            PageTableEntry,
+            PageTableMapper,
+        },
+        AccessPermission,
+        Address,
+        Frame,
+        Page,
+        PhysicalAddress,
+        VirtualAddress,
+    },
+};
+use ::alloc::vec::Vec;
+
+//==================================================================================================
+// Structures
+//==================================================================================================
+
+/// A frame allocator that hands out the frames of a fake physical memory.
+struct TestFrameAllocator {
+    /// Next frame that was never allocated.
+    next: Frame,
+    /// Frame that lies past the last one of the memory.
+    end: Frame,
+    /// Frames that were released.
+    free: Vec<Frame>,
+}
+
+//==================================================================================================
+// Implementations
+//==================================================================================================
+
+impl FrameAllocator for TestFrameAllocator {
+    fn allocate_frame(&mut self) -> Result<Frame, Error> {
+        if let Some(frame) = self.free.pop() {
+            return Ok(frame);
+        }
+        if self.next == self.end {
+            return Err(Error::new(ErrorCode::OutOfMemory, "no free frame"));
+        }
+        let frame: Frame = self.next;
+        self.next = self.next.checked_add(1)?;
+        Ok(frame)
+    }
+
+    fn deallocate_frame(&mut self, frame: Frame) -> Result<(), Error> {
+        self.free.push(frame);
+        Ok(())
+    }
+}
+
+//==================================================================================================
+// Unit Tests
+//==================================================================================================
+
+/// Attempts to encode and decode all access permissions in an i386 page table entry.
+#[test]
+fn test_i386_permission_round_trip() {
+    for raw in 0..=0b111_u8 {
+        let permission: AccessPermission = match AccessPermission::try_from(raw) {
+            Ok(permission) => permission,
+            Err(e) => panic!("failed to decode access permission (error={:?})", e),
+        };
+        let mut entry: I386PageTableEntry = I386PageTableEntry::default();
+        let encodable: bool =
+            permission.is_readable() || (!permission.is_writable() && !permission.is_executable());
+
+        match entry.set_permission(permission) {
+            Ok(()) if encodable => {
+                if entry.permission() != permission {
+                    panic!(
+                        "permission did not round-trip (expected={:?}, got={:?})",
+                        permission, entry
+                    );
+                }
+            },
+            Err(e) if !encodable && e.code == ErrorCode::OperationNotSupported => {},
+            result => {
+                panic!("unexpected result (permission={:?}, result={:?})", permission, result)
+            },
+        }
+    }
+}
+
+/// Attempts to encode an i386 page table entry and check its raw layout.
+#[test]
+fn test_i386_encoding() {
+    let mut entry: I386PageTableEntry = I386PageTableEntry::default();
+    let frame: Frame = Frame::containing_address(PhysicalAddress::new_unchecked(0x1234_5678));
+    if let Err(e) = entry.set_frame(frame) {
+        panic!("failed to set frame (error={:?})", e);
+    }
+    if let Err(e) = entry.set_permission(AccessPermission::RDWR) {
+        panic!("failed to set permission (error={:?})", e);
+    }
+    entry.set_user(true);
+    entry.set_dirty(true);
+    if entry.into_raw() != 0x1234_5000 | 0b10_0100_0111 {
+        panic!("unexpected raw entry (got={:#x})", entry.into_raw());
+    }
+
+    // Decode the entry from its raw value.
+    let entry: I386PageTableEntry = I386PageTableEntry::from_raw(entry.into_raw());
+    if entry.frame() != frame || !entry.is_user() || entry.is_accessed() {
+        panic!("unexpected decoded entry (entry={:?})", entry);
+    }
+
+    // Compute indexes of a virtual address.
+    let addr: VirtualAddress = VirtualAddress::new(0xc040_3000);
+    if I386PageTableEntry::directory_index(addr) != 0x301
+        || I386PageTableEntry::table_index(addr) != 3
+    {
+        panic!("unexpected indexes of virtual address (addr={:?})", addr);
+    }
+
+    // Set a frame that does not fit in 32 bits.
+    if usize::BITS > u32::BITS {
+        let frame: Frame = Frame::containing_address(PhysicalAddress::new_unchecked(1 << 32));
+        match I386PageTableEntry::default().set_frame(frame) {
+            Ok(()) => panic!("set frame that does not fit in entry"),
+            Err(e) if e.code == ErrorCode::BadAddress => {},
+            Err(e) => panic!("unexpected error code (error={:?})", e),
+        }
+    }
+}
+
+/// Attempts to map, translate and unmap pages with a software page-table mapper.
+#[test]
+fn test_mapper_map_translate_unmap() {
+    let mut mapper: PageTableMapper<TestFrameAllocator, FakePhysicalMemory> =
+        test_helper_new_mapper(8);
+    let page: Page = Page::containing_address(memory_layout::USER_BASE);
+    let frame: Frame = Frame::containing_address(PhysicalAddress::new_unchecked(0x7000));
+
+    // Map a user page.
+    if let Err(e) = mapper.map(page, frame, AccessPermission::RDWR) {
+        panic!("failed to map page (error={:?})", e);
+    }
+    match mapper.map(page, frame, AccessPermission::RDWR) {
+        Ok(()) => panic!("mapped page twice"),
+        Err(e) if e.code == ErrorCode::EntryExists => {},
+        Err(e) => panic!("unexpected error code (error={:?})", e),
+    }
+
+    // Translate an address inside of the page.
+    let addr: VirtualAddress =
+        VirtualAddress::new(memory_layout::USER_BASE.into_raw_value() + 0x123);
+    match mapper.translate(addr) {
+        Ok(phys) if phys.into_raw_value() == 0x7123 => {},
+        result => panic!("unexpected translation (addr={:?}, result={:?})", addr, result),
+    }
+
+    // Check that the page table was allocated and the entry is accessible from user mode.
+    if !mapper.allocator().free.is_empty() || mapper.allocator().next.number() != 2 {
+        panic!("unexpected page table allocation");
+    }
+    if !test_helper_read_entry(&mapper, page).is_user() {
+        panic!("user page is not accessible from user mode");
+    }
+
+    // Unmap the page and check that its page table was released.
+    match mapper.unmap(page) {
+        Ok(unmapped) if unmapped == frame => {},
+        result => panic!("unexpected unmap result (result={:?})", result),
+    }
+    if mapper.allocator().free.len() != 1 {
+        panic!("page table was not released");
+    }
+    match mapper.translate(addr) {
+        Ok(phys) => panic!("translated unmapped address (phys={:?})", phys),
+        Err(e) if e.code == ErrorCode::BadAddress => {},
+        Err(e) => panic!("unexpected error code (error={:?})", e),
+    }
+    match mapper.unmap(page) {
+        Ok(frame) => panic!("unmapped page twice (frame={:?})", frame),
+        Err(e) if e.code == ErrorCode::BadAddress => {},
+        Err(e) => panic!("unexpected error code (error={:?})", e),
+    }
+}
+
+/// Attempts to update access permissions of pages with a software page-table mapper.
+#[test]
+fn test_mapper_update_flags() {
+    let mut mapper: PageTableMapper<TestFrameAllocator, FakePhysicalMemory> =
+        test_helper_new_mapper(8);
+    let page: Page = Page::containing_address(VirtualAddress::new(0x0010_0000));
+    let frame: Frame = Frame::containing_address(PhysicalAddress::new_unchecked(0x6000));
+
+    // Update a page that is not mapped.
+    match mapper.update_flags(page, AccessPermission::RDONLY) {
+        Ok(()) => panic!("updated page that is not mapped"),
+        Err(e) if e.code == ErrorCode::BadAddress => {},
+        Err(e) => panic!("unexpected error code (error={:?})", e),
+    }
+
+    // Map a page with permissions that deny all accesses.
+    match mapper.map(page, frame, AccessPermission::default()) {
+        Ok(()) => panic!("mapped page that denies all accesses"),
+        Err(e) if e.code == ErrorCode::InvalidArgument => {},
+        Err(e) => panic!("unexpected error code (error={:?})", e),
+    }
+
+    // Map a kernel page and make it read-only.
+    if let Err(e) = mapper.map(page, frame, AccessPermission::RDWR) {
+        panic!("failed to map page (error={:?})", e);
+    }
+    if let Err(e) = mapper.update_flags(page, AccessPermission::RDONLY) {
+        panic!("failed to update page (error={:?})", e);
+    }
+    let entry: I386PageTableEntry = test_helper_read_entry(&mapper, page);
+    if entry.permission() != AccessPermission::RDONLY || entry.is_user() || entry.frame() != frame {
+        panic!("unexpected page table entry (entry={:?})", entry);
+    }
+
+    // Update a page with permissions that deny all accesses.
+    match mapper.update_flags(page, AccessPermission::default()) {
+        Ok(()) => panic!("updated page to deny all accesses"),
+        Err(e) if e.code == ErrorCode::InvalidArgument => {},
+        Err(e) => panic!("unexpected error code (error={:?})", e),
+    }
+}
+
+/// Attempts to map the last user page and the topmost page of the address space.
+#[test]
+fn test_mapper_map_boundaries() {
+    let mut mapper: PageTableMapper<TestFrameAllocator, FakePhysicalMemory> =
+        test_helper_new_mapper(8);
+    let frame: Frame = Frame::containing_address(PhysicalAddress::new_unchecked(0x7000));
+
+    // Map the last user page.
+    let last_user: Page =
+        Page::containing_address(VirtualAddress::new(memory_layout::USER_END.into_raw_value() - 1));
+    if let Err(e) = mapper.map(last_user, frame, AccessPermission::RDWR) {
+        panic!("failed to map last user page (error={:?})", e);
+    }
+    if !test_helper_read_entry(&mapper, last_user).is_user() {
+        panic!("last user page is not accessible from user mode");
+    }
+
+    // Map the topmost page.
+    let topmost: Page = Page::containing_address(VirtualAddress::new(0xffff_f000));
+    if let Err(e) = mapper.map(topmost, frame, AccessPermission::RDONLY) {
+        panic!("failed to map topmost page (error={:?})", e);
+    }
+    if test_helper_read_entry(&mapper, topmost).is_user() {
+        panic!("topmost page is accessible from user mode");
+    }
+    match mapper.translate(VirtualAddress::new(0xffff_ffff)) {
+        Ok(addr) if addr.into_raw_value() == 0x7fff => {},
+        result => panic!("unexpected translation (result={:?})", result),
+    }
+}
+
+/// Attempts to use virtual addresses that do not fit in 32 bits.
+#[test]
+fn test_mapper_bad_address() {
+    if usize::BITS <= u32::BITS {
+        return;
+    }
+
+    let mut mapper: PageTableMapper<TestFrameAllocator, FakePhysicalMemory> =
+        test_helper_new_mapper(8);
+    let low: Page = Page::containing_address(VirtualAddress::new(0x0010_0000));
+    let high: Page = Page::containing_address(VirtualAddress::new((1 << 32) | 0x0010_0000));
+    let frame: Frame = Frame::containing_address(PhysicalAddress::new_unchecked(0x6000));
+
+    // Map a page above the 32-bit address space.
+    match mapper.map(high, frame, AccessPermission::RDWR) {
+        Ok(()) => panic!("mapped page above 32-bit address space"),
+        Err(e) if e.code == ErrorCode::BadAddress => {},
+        Err(e) => panic!("unexpected error code (error={:?})", e),
+    }
+
+    // Check if a page above the 32-bit address space does not alias a mapped low page.
+    if let Err(e) = mapper.map(low, frame, AccessPermission::RDWR) {
+        panic!("failed to map page (error={:?})", e);
+    }
+    match mapper.translate(high.start_address()) {
+        Ok(addr) => panic!("translated address above 32-bit address space (addr={:?})", addr),
+        Err(e) if e.code == ErrorCode::BadAddress => {},
+        Err(e) => panic!("unexpected error code (error={:?})", e),
+    }
+    match mapper.update_flags(high, AccessPermission::RDONLY) {
+        Ok(()) => panic!("updated page above 32-bit address space"),
+        Err(e) if e.code == ErrorCode::BadAddress => {},
+        Err(e) => panic!("unexpected error code (error={:?})", e),
+    }
+    match mapper.unmap(high) {
+        Ok(frame) => panic!("unmapped page above 32-bit address space (frame={:?})", frame),
+        Err(e) if e.code == ErrorCode::BadAddress => {},
+        Err(e) => panic!("unexpected error code (error={:?})", e),
+    }
+
+    // Check if the low page is left untouched.
+    if test_helper_read_entry(&mapper, low).permission() != AccessPermission::RDWR {
+        panic!("low page was modified through an aliasing address");
+    }
+}
+
+/// Creates a mapper over a fake physical memory with `frames` frames.
+fn test_helper_new_mapper(
+    frames: usize,
+) -> PageTableMapper<TestFrameAllocator, FakePhysicalMemory> {
+    let start: Frame = Frame::containing_address(PhysicalAddress::new_unchecked(0));
+    let end: Frame = match start.checked_add(frames) {
+        Ok(end) => end,
+        Err(e) => panic!("failed to compute last frame (error={:?})", e),
+    };
+    let allocator: TestFrameAllocator = TestFrameAllocator {
+        next: start,
+        end,
+        free: Vec::new(),
+    };
+    let memory: FakePhysicalMemory = FakePhysicalMemory::new(frames * start.size());
+    match PageTableMapper::new(allocator, memory) {
+        Ok(mapper) => mapper,
+        Err(e) => panic!("failed to create mapper (error={:?})", e),
+    }
+}
+
+/// Reads the page table entry that maps a page by walking the fake physical memory.
+fn test_helper_read_entry(
+    mapper: &PageTableMapper<TestFrameAllocator, FakePhysicalMemory>,
+    page: Page,
+) -> I386PageTableEntry {
+    use crate::mm::paging::PhysicalMemory;
+
+    let entry_size: usize = core::mem::size_of::<I386PageTableEntry>();
+    let read = |addr: usize| -> I386PageTableEntry {
+        match mapper
+            .memory()
+            .read_u32(PhysicalAddress::new_unchecked(addr))
+        {
+            Ok(raw) => I386PageTableEntry::from_raw(raw),
+            Err(e) => panic!("failed to read entry (error={:?})", e),
+        }
+    };
+    let directory: usize = mapper.directory().start_address().into_raw_value();
+    let table: I386PageTableEntry =
+        read(directory + I386PageTableEntry::directory_index(page.start_address()) * entry_size);
+    read(
+        table.frame().start_address().into_raw_value()
+            + I386PageTableEntry::table_index(page.start_address()) * entry_size,
+    )
+}
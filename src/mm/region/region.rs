@@ -0,0 +1,303 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::{
+    config::memory_layout,
+    error::{
+        Error,
+        ErrorCode,
+    },
+    mm::{
+        self,
+        Address,
+        Alignment,
+        Page,
+        PageRange,
+        PageSize,
+        VirtualAddress,
+    },
+};
+
+//==================================================================================================
+// Structures
+//==================================================================================================
+
+///
+/// # Description
+///
+/// A region of memory that spans addresses from `start` up to, but not including, `end`.
+///
+/// Both bounds of a region are aligned to its alignment, and so are bounds of regions that are
+/// derived from it.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region<A: Address> {
+    /// Start address of the region.
+    start: A,
+    /// End address of the region.
+    end: A,
+    /// Alignment of the bounds of the region.
+    align: Alignment,
+}
+
+//==================================================================================================
+// Implementations
+//==================================================================================================
+
+impl<A: Address> Region<A> {
+    ///
+    /// # Description
+    ///
+    /// Instantiates a new [`Region`].
+    ///
+    /// # Parameters
+    ///
+    /// - `start`: Start address of the region.
+    /// - `end`: End address of the region.
+    /// - `align`: Alignment of the bounds of the region.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, the new region is returned. Upon failure, an error is returned instead.
+    ///
+    pub fn new(start: A, end: A, align: Alignment) -> Result<Self, Error> {
+        // Check if bounds are swapped.
+        if start > end {
+            return Err(Error::new(ErrorCode::InvalidArgument, "start lies after end"));
+        }
+
+        // Check if bounds are unaligned.
+        if !start.is_aligned(align)? || !end.is_aligned(align)? {
+            return Err(Error::new(ErrorCode::BadAddress, "unaligned region"));
+        }
+
+        Ok(Self { start, end, align })
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Instantiates a new [`Region`] from its start address and length.
+    ///
+    /// # Parameters
+    ///
+    /// - `start`: Start address of the region.
+    /// - `len`: Length of the region (in bytes).
+    /// - `align`: Alignment of the bounds of the region.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, the new region is returned. Upon failure, an error is returned instead.
+    ///
+    pub fn from_len(start: A, len: usize, align: Alignment) -> Result<Self, Error> {
+        let end: A = match start.clone().into_raw_value().checked_add(len) {
+            Some(end) if end <= A::max_addr() => A::from_raw_value(end)?,
+            _ => return Err(Error::new(ErrorCode::BadAddress, "region is out of bounds")),
+        };
+        Self::new(start, end, align)
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the start address of the target region.
+    ///
+    /// # Returns
+    ///
+    /// The start address of the target region.
+    ///
+    pub fn start(&self) -> A {
+        self.start.clone()
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the end address of the target region.
+    ///
+    /// # Returns
+    ///
+    /// The end address of the target region.
+    ///
+    pub fn end(&self) -> A {
+        self.end.clone()
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the alignment of the bounds of the target region.
+    ///
+    /// # Returns
+    ///
+    /// The alignment of the bounds of the target region.
+    ///
+    pub fn alignment(&self) -> Alignment {
+        self.align
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Returns the length of the target region.
+    ///
+    /// # Returns
+    ///
+    /// The length of the target region (in bytes).
+    ///
+    pub fn len(&self) -> usize {
+        self.end.clone().into_raw_value() - self.start.clone().into_raw_value()
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Checks if the target region is empty.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the target region spans no addresses, `false` otherwise.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Checks if the target region contains an address.
+    ///
+    /// # Parameters
+    ///
+    /// - `addr`: The address to check.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the target region contains `addr`, `false` otherwise.
+    ///
+    pub fn contains(&self, addr: &A) -> bool {
+        self.start <= *addr && *addr < self.end
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Checks if the target region overlaps with another region.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: The other region.
+    ///
+    /// # Returns
+    ///
+    /// `true` if both regions share at least one address, `false` otherwise.
+    ///
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.start < other.end && other.start < self.end
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Computes the intersection of the target region with another region.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: The other region.
+    ///
+    /// # Returns
+    ///
+    /// The region of addresses that lie in both regions, if any.
+    ///
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        if !self.overlaps(other) {
+            return None;
+        }
+
+        Some(Self {
+            start: self.start.clone().max(other.start.clone()),
+            end: self.end.clone().min(other.end.clone()),
+            align: self.align.min(other.align),
+        })
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Splits the target region in two at a given address.
+    ///
+    /// # Parameters
+    ///
+    /// - `addr`: The address to split at. It becomes the start address of the second region.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, the regions before and after `addr` are returned. Upon failure, an error is
+    /// returned instead.
+    ///
+    pub fn split_at(&self, addr: A) -> Result<(Self, Self), Error> {
+        // Check if the address lies outside of the region.
+        if addr < self.start || addr > self.end {
+            return Err(Error::new(ErrorCode::BadAddress, "address is out of bounds"));
+        }
+
+        // Check if the address is unaligned.
+        if !addr.is_aligned(self.align)? {
+            return Err(Error::new(ErrorCode::BadAddress, "unaligned address"));
+        }
+
+        Ok((
+            Self {
+                start: self.start.clone(),
+                end: addr.clone(),
+                align: self.align,
+            },
+            Self {
+                start: addr,
+                end: self.end.clone(),
+                align: self.align,
+            },
+        ))
+    }
+}
+
+impl Region<VirtualAddress> {
+    ///
+    /// # Description
+    ///
+    /// Returns the range of pages that the target region spans.
+    ///
+    /// # Returns
+    ///
+    /// Upon success, the range of pages that the target region spans is returned. Upon failure, an
+    /// error is returned instead.
+    ///
+    pub fn iter_pages<S: PageSize>(&self) -> Result<PageRange<S>, Error> {
+        // Check if bounds do not lie on page boundaries.
+        if !mm::is_aligned(self.start.into_raw_value(), S::ALIGNMENT)
+            || !mm::is_aligned(self.end.into_raw_value(), S::ALIGNMENT)
+        {
+            return Err(Error::new(ErrorCode::InvalidArgument, "region is not page-aligned"));
+        }
+
+        Ok(Page::range(Page::containing_address(self.start), Page::containing_address(self.end)))
+    }
+
+    ///
+    /// # Description
+    ///
+    /// Checks if the target region lies in user space.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the target region lies between [`memory_layout::USER_BASE`] and
+    /// [`memory_layout::USER_END`], `false` otherwise.
+    ///
+    pub fn is_user(&self) -> bool {
+        self.start >= memory_layout::USER_BASE && self.end <= memory_layout::USER_END
+    }
+}
@@ -0,0 +1,141 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use crate::{
+    config::memory_layout,
+    error::ErrorCode,
+    mm::{
+        Address,
+        Alignment,
+        Page,
+        PageRange,
+        PhysicalAddress,
+        Region,
+        Size4KiB,
+        VirtualAddress,
+    },
+};
+use ::alloc::vec::Vec;
+
+//==================================================================================================
+// Unit Tests
+//==================================================================================================
+
+/// Helper test function that creates a page-aligned [`Region`] of virtual memory.
+fn test_helper_create_region(start: usize, end: usize) -> Region<VirtualAddress> {
+    match Region::new(VirtualAddress::new(start), VirtualAddress::new(end), Alignment::Align4096) {
+        Ok(region) => region,
+        Err(e) => panic!("failed to create region (error={:?})", e),
+    }
+}
+
+/// Attempts to create regions with invalid bounds.
+#[test]
+fn test_new_invalid() {
+    match Region::new(VirtualAddress::new(0x2000), VirtualAddress::new(0x1000), Alignment::Align4) {
+        Ok(region) => panic!("created region with swapped bounds (region={:?})", region),
+        Err(e) if e.code == ErrorCode::InvalidArgument => {},
+        Err(e) => panic!("unexpected error code (error={:?})", e),
+    }
+    match Region::new(
+        VirtualAddress::new(0x1000),
+        VirtualAddress::new(0x2800),
+        Alignment::Align4096,
+    ) {
+        Ok(region) => panic!("created unaligned region (region={:?})", region),
+        Err(e) if e.code == ErrorCode::BadAddress => {},
+        Err(e) => panic!("unexpected error code (error={:?})", e),
+    }
+
+    // Create a region that does not fit in the physical address space.
+    let start: PhysicalAddress<20> = match PhysicalAddress::new(0xff000) {
+        Ok(addr) => addr,
+        Err(e) => panic!("failed to create physical address (error={:?})", e),
+    };
+    match Region::from_len(start, 0x2000, Alignment::Align4096) {
+        Ok(region) => panic!("created region past the address space (region={:?})", region),
+        Err(e) if e.code == ErrorCode::BadAddress => {},
+        Err(e) => panic!("unexpected error code (error={:?})", e),
+    }
+}
+
+/// Attempts to compute overlaps, intersections and splits of regions.
+#[test]
+fn test_overlap_and_split() {
+    let a: Region<VirtualAddress> = test_helper_create_region(0x1000, 0x4000);
+    let b: Region<VirtualAddress> = test_helper_create_region(0x3000, 0x6000);
+    let c: Region<VirtualAddress> = test_helper_create_region(0x4000, 0x5000);
+
+    if a.len() != 0x3000 || !a.contains(&VirtualAddress::new(0x3fff)) {
+        panic!("unexpected bounds (region={:?})", a);
+    }
+    if a.contains(&VirtualAddress::new(0x4000)) || a.overlaps(&c) || !a.overlaps(&b) {
+        panic!("unexpected overlaps (a={:?}, b={:?}, c={:?})", a, b, c);
+    }
+    match a.intersection(&b) {
+        Some(region) if region == test_helper_create_region(0x3000, 0x4000) => {},
+        result => panic!("unexpected intersection (result={:?})", result),
+    }
+    if a.intersection(&c).is_some() {
+        panic!("adjacent regions intersect");
+    }
+
+    // Split a region.
+    match a.split_at(VirtualAddress::new(0x2000)) {
+        Ok((lo, hi)) if lo.len() == 0x1000 && hi.start() == VirtualAddress::new(0x2000) => {},
+        result => panic!("unexpected split (result={:?})", result),
+    }
+    match a.split_at(VirtualAddress::new(0x2800)) {
+        Ok(regions) => panic!("split at unaligned address (regions={:?})", regions),
+        Err(e) if e.code == ErrorCode::BadAddress => {},
+        Err(e) => panic!("unexpected error code (error={:?})", e),
+    }
+    match a.split_at(VirtualAddress::new(0x5000)) {
+        Ok(regions) => panic!("split at address past the region (regions={:?})", regions),
+        Err(e) if e.code == ErrorCode::BadAddress => {},
+        Err(e) => panic!("unexpected error code (error={:?})", e),
+    }
+}
+
+/// Attempts to iterate over pages of regions in user space.
+#[test]
+fn test_iter_pages() {
+    let base: usize = memory_layout::USER_BASE.into_raw_value();
+    let region: Region<VirtualAddress> = test_helper_create_region(base, base + 0x3000);
+    if !region.is_user() {
+        panic!("region does not lie in user space (region={:?})", region);
+    }
+    let straddling: Region<VirtualAddress> =
+        test_helper_create_region(base - 0x1000, base + 0x1000);
+    if straddling.is_user() {
+        panic!("region lies in user space (region={:?})", straddling);
+    }
+
+    let pages: PageRange<Size4KiB> = match region.iter_pages() {
+        Ok(pages) => pages,
+        Err(e) => panic!("failed to iterate over pages (error={:?})", e),
+    };
+    let pages: Vec<Page> = pages.collect();
+    if pages.len() != 3 || pages[0].start_address() != memory_layout::USER_BASE {
+        panic!("unexpected pages (pages={:?})", pages);
+    }
+
+    // Iterate over pages of a region that is not page-aligned.
+    let region: Region<VirtualAddress> = match Region::new(
+        VirtualAddress::new(0x1800),
+        VirtualAddress::new(0x2000),
+        Alignment::Align2048,
+    ) {
+        Ok(region) => region,
+        Err(e) => panic!("failed to create region (error={:?})", e),
+    };
+    match region.iter_pages::<Size4KiB>() {
+        Ok(pages) => panic!("iterated over unaligned region (pages={:?})", pages),
+        Err(e) if e.code == ErrorCode::InvalidArgument => {},
+        Err(e) => panic!("unexpected error code (error={:?})", e),
+    }
+}
@@ -19,7 +19,7 @@ use crate::error::{
 ///
 /// A type that represents a process identifier.
 ///
-#[derive(Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(C)]
 pub struct ProcessIdentifier(u32);
 crate::static_assert_size!(ProcessIdentifier, 4);
@@ -74,6 +74,17 @@ impl From<ProcessIdentifier> for u32 {
     }
 }
 
+impl TryFrom<usize> for ProcessIdentifier {
+    type Error = Error;
+
+    fn try_from(raw_pid: usize) -> Result<Self, Self::Error> {
+        match u32::try_from(raw_pid) {
+            Ok(raw_pid) => Ok(ProcessIdentifier(raw_pid)),
+            Err(_) => Err(Error::new(ErrorCode::InvalidArgument, "invalid process identifier")),
+        }
+    }
+}
+
 impl TryFrom<i32> for ProcessIdentifier {
     type Error = Error;
 
@@ -0,0 +1,159 @@
+// Copyright(c) The Maintainers of Nanvix.
+// Licensed under the MIT License.
+
+//! Integration tests of [`SlabAllocator`] as the global allocator.
+//!
+//! These tests live in their own test binary, so that registering a global allocator does not
+//! affect unit tests of the library.
+
+//==================================================================================================
+// Imports
+//==================================================================================================
+
+use ::std::{
+    alloc::{
+        GlobalAlloc,
+        Layout,
+        System,
+    },
+    cell::Cell,
+    sync::Once,
+};
+use ::sys::collections::{
+    SlabAllocator,
+    SpinLock,
+};
+
+//==================================================================================================
+// Test Global Allocator
+//==================================================================================================
+
+/// Size of the memory region of the slab allocator that backs [`TestAllocator`].
+const HEAP_SIZE: usize = 1 << 20;
+
+/// Slab allocator that backs [`TestAllocator`].
+static SLAB_ALLOCATOR: SlabAllocator = SlabAllocator::new(SpinLock::new());
+
+/// Initializes [`SLAB_ALLOCATOR`].
+static SLAB_ALLOCATOR_INIT: Once = Once::new();
+
+std::thread_local! {
+    /// Whether allocations of the current thread are served by [`SLAB_ALLOCATOR`].
+    static USE_SLAB_ALLOCATOR: Cell<bool> = const { Cell::new(false) };
+}
+
+///
+/// # Description
+///
+/// Global allocator of this test binary. Allocations are served by [`SLAB_ALLOCATOR`] in threads
+/// that opt in through [`test_helper_with_slab_allocator()`], and by the system allocator
+/// otherwise.
+///
+struct TestAllocator;
+
+#[global_allocator]
+static TEST_ALLOCATOR: TestAllocator = TestAllocator;
+
+unsafe impl GlobalAlloc for TestAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        if USE_SLAB_ALLOCATOR.with(|cell| cell.get()) {
+            SLAB_ALLOCATOR.alloc(layout)
+        } else {
+            System.alloc(layout)
+        }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        if SLAB_ALLOCATOR.contains(ptr) {
+            SLAB_ALLOCATOR.dealloc(ptr, layout)
+        } else {
+            System.dealloc(ptr, layout)
+        }
+    }
+}
+
+/// A guard that routes allocations of the current thread to [`SLAB_ALLOCATOR`] while it lives.
+struct SlabAllocatorGuard;
+
+impl SlabAllocatorGuard {
+    /// Routes allocations of the current thread to [`SLAB_ALLOCATOR`].
+    fn new() -> Self {
+        USE_SLAB_ALLOCATOR.with(|cell| cell.set(true));
+        Self
+    }
+}
+
+impl Drop for SlabAllocatorGuard {
+    fn drop(&mut self) {
+        USE_SLAB_ALLOCATOR.with(|cell| cell.set(false));
+    }
+}
+
+/// Helper test function that runs a function with allocations served by [`SLAB_ALLOCATOR`].
+fn test_helper_with_slab_allocator<R>(f: impl FnOnce() -> R) -> R {
+    SLAB_ALLOCATOR_INIT.call_once(|| {
+        // NOTE: the memory region is intentionally leaked, as it backs a static allocator.
+        let layout: Layout = match Layout::from_size_align(HEAP_SIZE, SlabAllocator::MAX_BLOCK_SIZE)
+        {
+            Ok(layout) => layout,
+            Err(_) => panic!("invalid layout"),
+        };
+        let heap: *mut u8 = unsafe { System.alloc(layout) };
+        if heap.is_null() {
+            panic!("failed to allocate memory region");
+        }
+        if let Err(e) = unsafe { SLAB_ALLOCATOR.init(heap, HEAP_SIZE) } {
+            panic!("failed to initialize slab allocator (error={:?})", e);
+        }
+    });
+
+    // NOTE: allocations are routed back to the system allocator even if `f` panics.
+    let _guard: SlabAllocatorGuard = SlabAllocatorGuard::new();
+    f()
+}
+
+//==================================================================================================
+// Integration Tests
+//==================================================================================================
+
+/// Attempts to use a [`SlabAllocator`] as the global allocator of [`Box`] and [`Vec`].
+#[test]
+fn test_global_alloc_box_and_vec() {
+    test_helper_with_slab_allocator(|| {
+        // Allocate a box.
+        let boxed: Box<u64> = Box::new(42);
+        if !SLAB_ALLOCATOR.contains(&*boxed as *const u64 as *const u8) {
+            panic!("box was not allocated by the slab allocator");
+        }
+
+        // Grow a vector through several size classes.
+        let mut vector: Vec<u32> = Vec::new();
+        for i in 0..1000 {
+            vector.push(i);
+            if !SLAB_ALLOCATOR.contains(vector.as_ptr() as *const u8) {
+                panic!("vector was not allocated by the slab allocator (len={})", vector.len());
+            }
+        }
+
+        // Check if contents survived reallocations.
+        if *boxed != 42 || !vector.iter().copied().eq(0..1000) {
+            panic!("contents were corrupted");
+        }
+    });
+}
+
+/// Attempts to free memory that was allocated by a [`SlabAllocator`] outside of it.
+#[test]
+fn test_global_dealloc_outside() {
+    let boxed: Box<u64> = test_helper_with_slab_allocator(|| Box::new(7));
+    if !SLAB_ALLOCATOR.contains(&*boxed as *const u64 as *const u8) {
+        panic!("box was not allocated by the slab allocator");
+    }
+
+    // Drop the box while allocations are served by the system allocator.
+    drop(boxed);
+    let boxed: Box<u64> = Box::new(7);
+    if SLAB_ALLOCATOR.contains(&*boxed as *const u64 as *const u8) {
+        panic!("box was allocated by the slab allocator outside of the helper");
+    }
+}